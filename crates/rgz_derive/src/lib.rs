@@ -1,9 +1,13 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use regex::Regex;
-use syn;
 
-#[proc_macro_derive(GzMessage)]
+/// Implement `rgz_msgs::GzMessage`, and `rgz_msgs::HasHeader` for the structs
+/// with a `header: Option<rgz_msgs::Header>` field, or an `Option<rgz_msgs::Header>`
+/// field marked with `#[gz(header)]`.
+///
+/// The full protobuf name of the message is required, set it with
+/// `#[gz(type_name = "my.pkg.Message")]`.
+#[proc_macro_derive(GzMessage, attributes(gz))]
 pub fn gz_message_macro_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
@@ -13,15 +17,140 @@ pub fn gz_message_macro_derive(input: TokenStream) -> TokenStream {
 fn impl_gz_message_macro(ast: &syn::DeriveInput) -> TokenStream {
     // let name = &ast.ident;
     let name = &ast.ident;
-    let name_str = name.to_string();
-    let re = Regex::new(r"(V)$").unwrap();
-    let replace_name = re.replace(&name_str, "_V").to_string();
-    let type_name = format!("gz.msgs.{}", replace_name);
-
-    let gen = quote! {
-        impl crate::GzMessage for #name {
-            const TYPE_NAME: &'static str = #type_name;
+    let type_name = match attr_type_name(ast) {
+        Ok(Some(type_name)) => type_name,
+        Ok(None) => {
+            let err = syn::Error::new_spanned(name, "missing #[gz(type_name = \"...\")]");
+            return err.to_compile_error().into();
+        }
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let header = match header_field(ast) {
+        Ok(header) => header,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let gen = if let Some(header) = header {
+        quote! {
+            impl ::rgz_msgs::GzMessage for #name {
+                const TYPE_NAME: &'static str = #type_name;
+
+                fn header_field_mut(
+                    &mut self,
+                ) -> ::core::option::Option<&mut ::rgz_msgs::Header> {
+                    ::core::option::Option::Some(::rgz_msgs::HasHeader::header_mut(self))
+                }
+            }
+
+            impl ::rgz_msgs::HasHeader for #name {
+                fn header(&self) -> ::core::option::Option<&::rgz_msgs::Header> {
+                    self.#header.as_ref()
+                }
+
+                fn header_mut(&mut self) -> &mut ::rgz_msgs::Header {
+                    self.#header.get_or_insert_with(::core::default::Default::default)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::rgz_msgs::GzMessage for #name {
+                const TYPE_NAME: &'static str = #type_name;
+            }
         }
     };
     gen.into()
 }
+
+// The `Option<rgz_msgs::Header>` field named `header` or marked with
+// `#[gz(header)]`. Other `Header` types are left alone, as the path of a type
+// can't be resolved here.
+fn header_field(ast: &syn::DeriveInput) -> syn::Result<Option<&syn::Ident>> {
+    let syn::Data::Struct(data) = &ast.data else {
+        return Ok(None);
+    };
+    for field in &data.fields {
+        let Some(ident) = field.ident.as_ref() else {
+            continue;
+        };
+        if attr_header(field)? || (ident == "header" && is_rgz_header(&field.ty)) {
+            return Ok(Some(ident));
+        }
+    }
+    Ok(None)
+}
+
+// Read `#[gz(header)]` on a field.
+fn attr_header(field: &syn::Field) -> syn::Result<bool> {
+    let mut header = false;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("gz")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("header") {
+                header = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported gz attribute"))
+            }
+        })?;
+    }
+    Ok(header)
+}
+
+// Whether the type is `Option<rgz_msgs::Header>` or `Option<::rgz_msgs::Header>`.
+fn is_rgz_header(ty: &syn::Type) -> bool {
+    let Some(syn::Type::Path(path)) = option_inner(ty) else {
+        return false;
+    };
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    path.qself.is_none() && segments == ["rgz_msgs", "Header"]
+}
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+// `T` of `Option<T>`, whatever the path of `Option`.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_segment(ty)?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Read `#[gz(type_name = "...")]`, at most one per struct.
+fn attr_type_name(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let mut type_names = Vec::new();
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("gz")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type_name") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                type_names.push(value.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported gz attribute"))
+            }
+        })?;
+    }
+    if type_names.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "more than one #[gz(type_name)] attribute",
+        ));
+    }
+    Ok(type_names.pop())
+}
@@ -1,6 +1,19 @@
+//! Generates the gz.msgs types from the protos vendored for a Gazebo release.
+//!
+//! The protos of a release are copied from gazebosim/gz-msgs into
+//! `proto/<release>/gz/msgs`, with its Apache-2.0 license, and the release is
+//! selected by the cargo feature of the same name. Without the protos or protoc, the code pre-generated in
+//! `generated/<release>` is used, so that the crate builds offline.
+use std::env;
 use std::error::Error;
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost::Message;
+use prost_types::{DescriptorProto, FileDescriptorSet};
 
 fn get_proto_file_paths(dir_path: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
     let mut file_paths = Vec::new();
@@ -17,15 +30,232 @@ fn get_proto_file_paths(dir_path: &str) -> Result<Vec<PathBuf>, Box<dyn Error>>
             }
         }
     }
+    // In the same order on every file system, for the pre-generated code.
+    file_paths.sort();
     Ok(file_paths)
 }
 
+/// Gazebo releases with vendored protos, one cargo feature each: garden has
+/// the protos of gz-msgs 9.5.1 and harmonic the ones of gz-msgs 10.3.2.
+const RELEASES: &[&str] = &["garden", "harmonic"];
+
+fn selected_release() -> Result<&'static str, Box<dyn Error>> {
+    let selected: Vec<&str> = RELEASES
+        .iter()
+        .copied()
+        .filter(|release| {
+            env::var_os(format!("CARGO_FEATURE_{}", release.to_uppercase())).is_some()
+        })
+        .collect();
+    match selected[..] {
+        [release] => Ok(release),
+        [] => Err(format!(
+            "Select a Gazebo release with one of the features {:?}",
+            RELEASES
+        )
+        .into()),
+        _ => Err(format!("Select only one Gazebo release, got {:?}", selected).into()),
+    }
+}
+
+// Return `None` if protoc is not installed.
+fn load_file_descriptor_set(
+    file_paths: &[PathBuf],
+    include: &str,
+    out_dir: &Path,
+) -> Result<Option<FileDescriptorSet>, Box<dyn Error>> {
+    let fds_path = out_dir.join("gz_msgs_fds.bin");
+    let status = Command::new(prost_build::protoc_from_env())
+        .arg("--include_imports")
+        .arg("--include_source_info")
+        .arg(format!("--descriptor_set_out={}", fds_path.display()))
+        .arg(format!("-I{}", include))
+        .args(file_paths)
+        .status();
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => return Err(format!("protoc failed: {}", status).into()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    }
+    let fds = FileDescriptorSet::decode(fs::read(fds_path)?.as_slice())?;
+    Ok(Some(fds))
+}
+
+fn copy_generated(release: &str, out_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let generated_dir = Path::new("generated").join(release);
+    if !generated_dir.is_dir() {
+        return Err(format!(
+            "No gz.msgs code for {}: vendor its protos into proto/{}/gz/msgs and install protoc",
+            release, release
+        )
+        .into());
+    }
+    for file in ["gz.msgs.rs", "gz.msgs.bin"] {
+        fs::copy(generated_dir.join(file), out_dir.join(file))?;
+    }
+    let fds = FileDescriptorSet::decode(fs::read(out_dir.join("gz.msgs.bin"))?.as_slice())?;
+    write_type_names(&fds, out_dir)
+}
+
+// Identifiers as made by prost-build: raw for the keywords, suffixed for the
+// ones that can't be raw.
+fn sanitize(ident: String) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "else", "enum", "false", "fn", "for", "if", "impl",
+        "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static",
+        "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "dyn", "abstract",
+        "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized",
+        "virtual", "yield", "async", "await", "try",
+    ];
+    match ident.as_str() {
+        "_" | "super" | "self" | "Self" | "extern" | "crate" => format!("{}_", ident),
+        keyword if KEYWORDS.contains(&keyword) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
+
+// Full protobuf name and Rust path of a message and its nested messages.
+fn message_paths(
+    prefix: &str,
+    module: &str,
+    message: &DescriptorProto,
+    paths: &mut Vec<(String, String)>,
+) {
+    let name = format!("{}.{}", prefix, message.name());
+    let ident = sanitize(message.name().to_upper_camel_case());
+    paths.push((name.clone(), format!("{}::{}", module, ident)));
+    let module = format!("{}::{}", module, sanitize(message.name().to_snake_case()));
+    for nested in &message.nested_type {
+        if nested.options.as_ref().and_then(|o| o.map_entry) != Some(true) {
+            message_paths(&name, &module, nested, paths);
+        }
+    }
+}
+
+// Table of the full names and `GzMessage::TYPE_NAME` of the gz.msgs types,
+// checked by the tests.
+fn write_type_names(fds: &FileDescriptorSet, out_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let mut paths = Vec::new();
+    for file in fds.file.iter().filter(|file| file.package() == "gz.msgs") {
+        for message in &file.message_type {
+            message_paths(file.package(), "crate", message, &mut paths);
+        }
+    }
+    let mut table = String::from("&[\n");
+    for (name, path) in paths {
+        table.push_str(&format!(
+            "    (\"{}\", <{} as crate::GzMessage>::TYPE_NAME),\n",
+            name, path
+        ));
+    }
+    table.push_str("]\n");
+    fs::write(out_dir.join("gz.msgs.type_names.rs"), table)?;
+    Ok(())
+}
+
+include!("src/build/names.rs");
+
+// Paths of the `google.protobuf.Any` fields, which have no serde support.
+fn any_fields(prefix: &str, message: &DescriptorProto, paths: &mut Vec<String>) {
+    let name = format!("{}.{}", prefix, message.name());
+    for field in &message.field {
+        if field.type_name() == ".google.protobuf.Any" {
+            paths.push(format!("{}.{}", name, field.name()));
+        }
+    }
+    for nested in &message.nested_type {
+        any_fields(&name, nested, paths);
+    }
+}
+
+// Paths of the `header` fields, which the derive can't tell from other `Header`
+// types in this crate.
+fn header_fields(prefix: &str, message: &DescriptorProto, paths: &mut Vec<String>) {
+    let name = format!("{}.{}", prefix, message.name());
+    for field in &message.field {
+        if field.name() == "header" && field.type_name() == ".gz.msgs.Header" {
+            paths.push(format!("{}.{}", name, field.name()));
+        }
+    }
+    for nested in &message.nested_type {
+        header_fields(&name, nested, paths);
+    }
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
-    let gz_msgs_file_paths = get_proto_file_paths("gz-msgs/proto/gz/msgs")?;
+    let release = selected_release()?;
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    let proto_dir = format!("proto/{}", release);
+    // Cargo reruns the script on every build for a missing path.
+    for dir in [proto_dir.clone(), format!("generated/{}", release)] {
+        if Path::new(&dir).exists() {
+            println!("cargo:rerun-if-changed={}", dir);
+        }
+    }
+
+    let msgs_dir = format!("{}/gz/msgs", proto_dir);
+    let gz_msgs_file_paths = if Path::new(&msgs_dir).is_dir() {
+        get_proto_file_paths(&msgs_dir)?
+    } else {
+        Vec::new()
+    };
+    if gz_msgs_file_paths.is_empty() {
+        return copy_generated(release, &out_dir);
+    }
+    let fds = match load_file_descriptor_set(&gz_msgs_file_paths, &proto_dir, &out_dir)? {
+        Some(fds) => fds,
+        None => {
+            println!("cargo:warning=protoc not found, using the pre-generated gz.msgs code");
+            return copy_generated(release, &out_dir);
+        }
+    };
+
+    let mut config = prost_build::Config::new();
+    config.type_attribute(
+        ".",
+        "#[cfg_attr(feature = \"serde\", derive(::serde::Serialize, ::serde::Deserialize))]",
+    );
+    config.message_attribute(".", "#[derive(::rgz_derive::GzMessage)]");
+    // Proto3 fields are optional, so are the fields of config files.
+    config.message_attribute(".", "#[cfg_attr(feature = \"serde\", serde(default))]");
+
+    // The identifiers are camel cased by prost (`Pose_V`, `GUI`) and lose the
+    // enclosing messages, so the derive takes the full name from the descriptor.
+    let mut names = Vec::new();
+    let mut any_paths = Vec::new();
+    let mut header_paths = Vec::new();
+    for file in &fds.file {
+        let prefix = format!(".{}", file.package());
+        for message in &file.message_type {
+            message_names(file.package(), message, &mut names);
+            any_fields(&prefix, message, &mut any_paths);
+            header_fields(&prefix, message, &mut header_paths);
+        }
+    }
+    check_suffixes(&names)?;
+    for name in names {
+        config.message_attribute(&name, format!("#[gz(type_name = \"{}\")]", name));
+    }
+
+    for path in header_paths {
+        config.field_attribute(path, "#[gz(header)]");
+    }
+    for path in any_paths {
+        config.field_attribute(
+            path,
+            "#[cfg_attr(feature = \"serde\", serde(with = \"crate::any_serde\"))]",
+        );
+    }
+
+    // Embedded by the registry, without the comments.
+    let mut registry_fds = fds.clone();
+    for file in &mut registry_fds.file {
+        file.source_code_info = None;
+    }
+    fs::write(out_dir.join("gz.msgs.bin"), registry_fds.encode_to_vec())?;
+    write_type_names(&registry_fds, &out_dir)?;
 
-    prost_build::Config::new()
-        .message_attribute(".", "#[derive(::rgz_derive::GzMessage)]")
-        .out_dir("src")
-        .compile_protos(&gz_msgs_file_paths, &[&"gz-msgs/proto"])?;
+    config.out_dir(&out_dir).compile_fds(fds)?;
     Ok(())
 }
@@ -0,0 +1,32 @@
+//! Serde support of the `google.protobuf.Any` fields, which prost-types lacks.
+use prost_types::Any;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct AnyDef {
+    type_url: String,
+    value: Vec<u8>,
+}
+
+pub(crate) fn serialize<S: Serializer>(
+    any: &Option<Any>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    any.as_ref()
+        .map(|any| AnyDef {
+            type_url: any.type_url.clone(),
+            value: any.value.clone(),
+        })
+        .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Any>, D::Error> {
+    let any = Option::<AnyDef>::deserialize(deserializer)?;
+    Ok(any.map(|any| Any {
+        type_url: any.type_url,
+        value: any.value,
+    }))
+}
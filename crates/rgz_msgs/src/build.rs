@@ -0,0 +1,194 @@
+//! Compile the protos of custom messages in a build script, with
+//! `#[derive(GzMessage)]` attached so they can be used with a `Node`.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> anyhow::Result<()> {
+//!     rgz_msgs::build::compile_protos(&["proto/my_pkg/person.proto"], &["proto"])
+//! }
+//! ```
+//!
+//! The gz.msgs types imported by the protos refer to the types of this crate,
+//! the gz-msgs protos only have to be in the include paths.
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, bail, Result};
+use prost::Message;
+use prost_build::Config;
+use prost_types::FileDescriptorSet;
+
+use names::{check_suffixes, message_names};
+
+mod names;
+
+const GZ_MSGS_PACKAGE: &str = "gz.msgs";
+
+/// Compile the protos into `OUT_DIR`, one file per package.
+pub fn compile_protos(protos: &[impl AsRef<Path>], includes: &[impl AsRef<Path>]) -> Result<()> {
+    compile_protos_with_config(Config::new(), protos, includes)
+}
+
+/// Like `compile_protos()`, with other prost-build options.
+pub fn compile_protos_with_config(
+    config: Config,
+    protos: &[impl AsRef<Path>],
+    includes: &[impl AsRef<Path>],
+) -> Result<()> {
+    let fds = load_file_descriptor_set(protos, includes)?;
+    compile_fds_with_config(config, fds)
+}
+
+/// Like `compile_protos_with_config()`, from the descriptors of the protos and
+/// their imports, as written by `protoc --include_imports`.
+pub fn compile_fds_with_config(mut config: Config, mut fds: FileDescriptorSet) -> Result<()> {
+    // Generated by this crate already.
+    fds.file.retain(|file| file.package() != GZ_MSGS_PACKAGE);
+
+    config.extern_path(format!(".{}", GZ_MSGS_PACKAGE), "::rgz_msgs");
+    config.message_attribute(".", "#[derive(::rgz_msgs::GzMessage)]");
+
+    // The derive takes the full name of the messages from `#[gz(type_name)]`,
+    // as the identifiers lose the package and the enclosing messages.
+    let mut names = Vec::new();
+    for file in &fds.file {
+        for message in &file.message_type {
+            message_names(file.package(), message, &mut names);
+        }
+    }
+    check_suffixes(&names).map_err(|err| anyhow!(err))?;
+    for name in names {
+        config.message_attribute(&name, format!("#[gz(type_name = \"{}\")]", name));
+    }
+
+    config.compile_fds(fds)?;
+    Ok(())
+}
+
+fn load_file_descriptor_set(
+    protos: &[impl AsRef<Path>],
+    includes: &[impl AsRef<Path>],
+) -> Result<FileDescriptorSet> {
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| anyhow!("OUT_DIR is not set"))?;
+    let fds_path = Path::new(&out_dir).join("rgz_msgs_build_fds.bin");
+
+    let mut cmd = Command::new(prost_build::protoc_from_env());
+    cmd.arg("--include_imports")
+        .arg("--include_source_info")
+        .arg(format!("--descriptor_set_out={}", fds_path.display()));
+    for include in includes {
+        cmd.arg(format!("-I{}", include.as_ref().display()));
+    }
+    if let Some(protoc_include) = prost_build::protoc_include_from_env() {
+        cmd.arg(format!("-I{}", protoc_include.display()));
+    }
+    for proto in protos {
+        cmd.arg(proto.as_ref());
+        println!("cargo:rerun-if-changed={}", proto.as_ref().display());
+    }
+
+    let output = match cmd.output() {
+        Ok(output) => output,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            bail!("{}", prost_build::error_message_protoc_not_found())
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if !output.status.success() {
+        bail!("protoc failed: {}", String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(FileDescriptorSet::decode(fs::read(fds_path)?.as_slice())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::{DescriptorProto, FieldDescriptorProto, FileDescriptorProto};
+
+    use super::*;
+    use crate::registry;
+
+    // The descriptor of:
+    //
+    //     syntax = "proto3";
+    //     package my_pkg;
+    //     import "<header_file>";
+    //     message Stamped {
+    //       message Inner { double x = 1; }
+    //       gz.msgs.Header header = 1;
+    //       Inner inner = 2;
+    //     }
+    fn fixture(header_file: &str) -> FileDescriptorProto {
+        let field = |name: &str, number, r#type: Type, type_name: &str| FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(r#type as i32),
+            type_name: (!type_name.is_empty()).then(|| type_name.to_string()),
+            json_name: Some(name.to_string()),
+            ..Default::default()
+        };
+        FileDescriptorProto {
+            name: Some("my_pkg/stamped.proto".to_string()),
+            package: Some("my_pkg".to_string()),
+            dependency: vec![header_file.to_string()],
+            message_type: vec![DescriptorProto {
+                name: Some("Stamped".to_string()),
+                field: vec![
+                    field("header", 1, Type::Message, ".gz.msgs.Header"),
+                    field("inner", 2, Type::Message, ".my_pkg.Stamped.Inner"),
+                ],
+                nested_type: vec![DescriptorProto {
+                    name: Some("Inner".to_string()),
+                    field: vec![field("x", 1, Type::Double, "")],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compile_fds() {
+        // gz/msgs/header.proto, with gz/msgs/time.proto as its dependency.
+        let header_file = registry::message_descriptor("gz.msgs.Header")
+            .unwrap()
+            .parent_file();
+        let mut fds = FileDescriptorSet::default();
+        for file in header_file.dependencies().chain([header_file.clone()]) {
+            fds.file.push(file.file_descriptor_proto().clone());
+        }
+        fds.file.push(fixture(header_file.name()));
+
+        let out_dir = std::env::temp_dir().join(format!("rgz_msgs_build_{}", std::process::id()));
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut config = Config::new();
+        config.out_dir(&out_dir);
+        compile_fds_with_config(config, fds).unwrap();
+
+        // gz.msgs is not generated again.
+        assert!(!out_dir.join("gz.msgs.rs").exists());
+        let code = fs::read_to_string(out_dir.join("my_pkg.rs")).unwrap();
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        assert!(code.contains("::core::option::Option<::rgz_msgs::Header>"));
+        assert!(code.contains("#[derive(::rgz_msgs::GzMessage)]"));
+        assert!(code.contains("#[gz(type_name = \"my_pkg.Stamped\")]"));
+        assert!(code.contains("#[gz(type_name = \"my_pkg.Stamped.Inner\")]"));
+        // One name per message, not the ones of the enclosing messages.
+        assert_eq!(code.matches("#[gz(type_name").count(), 2);
+        assert!(!code.contains("struct Header"));
+    }
+
+    #[test]
+    fn test_check_suffixes() {
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        assert!(check_suffixes(&names(&["my_pkg.Stamped", "my_pkg.Stamped.Inner"])).is_ok());
+        assert!(check_suffixes(&names(&["Stamped", "my_pkg.Stamped"])).is_err());
+        assert!(check_suffixes(&names(&["a.Stamped", "b.a.Stamped"])).is_err());
+    }
+}
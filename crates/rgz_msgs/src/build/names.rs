@@ -0,0 +1,36 @@
+// Full names of the messages, shared by `rgz_msgs::build` and the build script
+// of this crate, which includes this file. Keep it free of `use` items.
+
+// Full names of a message and its nested messages, parents first, without the
+// leading dot.
+pub(crate) fn message_names(
+    package: &str,
+    message: &prost_types::DescriptorProto,
+    names: &mut Vec<String>,
+) {
+    let name = if package.is_empty() {
+        message.name().to_string()
+    } else {
+        format!("{}.{}", package, message.name())
+    };
+    names.push(name.clone());
+    for nested in &message.nested_type {
+        // Skip the entries generated for map fields.
+        if nested.options.as_ref().and_then(|o| o.map_entry) != Some(true) {
+            message_names(&name, nested, names);
+        }
+    }
+}
+
+// prost-build applies the attribute of a name without the leading dot to the
+// message itself, not to its nested messages as with `.name`, but also to the
+// messages of other packages whose full name ends with it.
+pub(crate) fn check_suffixes(names: &[String]) -> Result<(), String> {
+    for name in names {
+        let suffix = format!(".{}", name);
+        if let Some(other) = names.iter().find(|other| other.ends_with(&suffix)) {
+            return Err(format!("The full name [{}] ends with the one of [{}]", other, name));
+        }
+    }
+    Ok(())
+}
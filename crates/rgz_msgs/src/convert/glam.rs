@@ -0,0 +1,114 @@
+use ::glam::{DAffine3, DQuat, DVec2, DVec3, Quat, Vec3, Vec4};
+
+use crate::{Color, Pose, Quaternion, Vector2d, Vector3d};
+
+impl From<DVec2> for Vector2d {
+    fn from(v: DVec2) -> Self {
+        Vector2d::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2d> for DVec2 {
+    fn from(v: Vector2d) -> Self {
+        DVec2::new(v.x, v.y)
+    }
+}
+
+impl From<DVec3> for Vector3d {
+    fn from(v: DVec3) -> Self {
+        Vector3d::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3d> for DVec3 {
+    fn from(v: Vector3d) -> Self {
+        DVec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for Vector3d {
+    fn from(v: Vec3) -> Self {
+        DVec3::from(v).into()
+    }
+}
+
+impl From<Vector3d> for Vec3 {
+    fn from(v: Vector3d) -> Self {
+        DVec3::from(v).as_vec3()
+    }
+}
+
+impl From<DQuat> for Quaternion {
+    fn from(q: DQuat) -> Self {
+        Quaternion::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quaternion> for DQuat {
+    fn from(q: Quaternion) -> Self {
+        DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quat> for Quaternion {
+    fn from(q: Quat) -> Self {
+        q.as_f64().into()
+    }
+}
+
+impl From<Quaternion> for Quat {
+    fn from(q: Quaternion) -> Self {
+        DQuat::from(q).as_f32()
+    }
+}
+
+impl From<DAffine3> for Pose {
+    /// The scale of the transform is dropped.
+    fn from(t: DAffine3) -> Self {
+        let (_, rotation, translation) = t.to_scale_rotation_translation();
+        Pose::new(translation, rotation)
+    }
+}
+
+impl From<Pose> for DAffine3 {
+    fn from(pose: Pose) -> Self {
+        DAffine3::from_rotation_translation(
+            pose.orientation_or_identity().into(),
+            pose.position_or_default().into(),
+        )
+    }
+}
+
+impl From<Vec4> for Color {
+    fn from(c: Vec4) -> Self {
+        Color::rgba(c.x, c.y, c.z, c.w)
+    }
+}
+
+impl From<Color> for Vec4 {
+    fn from(c: Color) -> Self {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glam() {
+        let pose = Pose::from_xyz_rpy(1.0, 2.0, 3.0, 0.0, 0.0, 1.0);
+        let t = DAffine3::from(pose.clone());
+        assert_eq!(t.translation, DVec3::new(1.0, 2.0, 3.0));
+        assert!(t
+            .transform_vector3(DVec3::X)
+            .abs_diff_eq(DVec3::new(1f64.cos(), 1f64.sin(), 0.0), 1e-9));
+        let back = Pose::from(t);
+        assert!(DQuat::from(back.orientation_or_identity())
+            .abs_diff_eq(DQuat::from_rotation_z(1.0), 1e-9));
+        assert_eq!(
+            Vec3::from(Vector3d::new(1.0, 2.0, 3.0)),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Constructors and conversions between the geometry messages and std/math types.
+//!
+//! Angles are in radians and quaternion arrays are ordered `[x, y, z, w]`, as
+//! in glam and nalgebra.
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+use anyhow::{bail, Error};
+
+use crate::{Color, Duration, Pose, Quaternion, Time, Twist, Vector2d, Vector3d};
+
+impl Vector2d {
+    pub fn new(x: f64, y: f64) -> Self {
+        Vector2d {
+            x,
+            y,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<[f64; 2]> for Vector2d {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Vector2d::new(x, y)
+    }
+}
+
+impl From<Vector2d> for [f64; 2] {
+    fn from(v: Vector2d) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl Vector3d {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Vector3d {
+            x,
+            y,
+            z,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<[f64; 3]> for Vector3d {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Vector3d::new(x, y, z)
+    }
+}
+
+impl From<(f64, f64, f64)> for Vector3d {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Vector3d::new(x, y, z)
+    }
+}
+
+impl From<Vector3d> for [f64; 3] {
+    fn from(v: Vector3d) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl Quaternion {
+    pub fn from_xyzw(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Quaternion {
+            x,
+            y,
+            z,
+            w,
+            ..Default::default()
+        }
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::from_xyzw(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Rotation about the fixed X, Y then Z axes, like `gz::math::Quaternion::Euler`.
+    pub fn from_rpy(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        Quaternion::from_xyzw(
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        )
+    }
+
+    /// Inverse of `from_rpy()`, returns `(roll, pitch, yaw)`.
+    pub fn to_rpy(&self) -> (f64, f64, f64) {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        (roll, pitch, yaw)
+    }
+}
+
+impl From<[f64; 4]> for Quaternion {
+    fn from([x, y, z, w]: [f64; 4]) -> Self {
+        Quaternion::from_xyzw(x, y, z, w)
+    }
+}
+
+impl From<Quaternion> for [f64; 4] {
+    fn from(q: Quaternion) -> Self {
+        [q.x, q.y, q.z, q.w]
+    }
+}
+
+impl Pose {
+    pub fn new(position: impl Into<Vector3d>, orientation: impl Into<Quaternion>) -> Self {
+        Pose {
+            position: Some(position.into()),
+            orientation: Some(orientation.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_xyz_rpy(x: f64, y: f64, z: f64, roll: f64, pitch: f64, yaw: f64) -> Self {
+        Pose::new(
+            Vector3d::new(x, y, z),
+            Quaternion::from_rpy(roll, pitch, yaw),
+        )
+    }
+
+    /// The position, or the origin if unset.
+    pub fn position_or_default(&self) -> Vector3d {
+        self.position.clone().unwrap_or_default()
+    }
+
+    /// The orientation, or the identity if unset.
+    pub fn orientation_or_identity(&self) -> Quaternion {
+        self.orientation
+            .clone()
+            .unwrap_or_else(Quaternion::identity)
+    }
+}
+
+impl Twist {
+    pub fn new(linear: impl Into<Vector3d>, angular: impl Into<Vector3d>) -> Self {
+        Twist {
+            linear: Some(linear.into()),
+            angular: Some(angular.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Color {
+    pub fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color {
+            r,
+            g,
+            b,
+            a,
+            ..Default::default()
+        }
+    }
+
+    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Color::rgba(r, g, b, 1.0)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        Color::rgba(r, g, b, a)
+    }
+}
+
+impl From<[f32; 3]> for Color {
+    fn from([r, g, b]: [f32; 3]) -> Self {
+        Color::rgb(r, g, b)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(c: Color) -> Self {
+        [c.r, c.g, c.b, c.a]
+    }
+}
+
+fn to_std_duration(sec: i64, nsec: i32) -> Result<std::time::Duration, Error> {
+    if sec < 0 || nsec < 0 {
+        bail!("Negative time [{}s {}ns]", sec, nsec);
+    }
+    Ok(std::time::Duration::from_secs(sec as u64) + std::time::Duration::from_nanos(nsec as u64))
+}
+
+impl From<std::time::Duration> for Time {
+    fn from(d: std::time::Duration) -> Self {
+        Time {
+            sec: d.as_secs() as i64,
+            nsec: d.subsec_nanos() as i32,
+        }
+    }
+}
+
+impl TryFrom<Time> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(t: Time) -> Result<Self, Self::Error> {
+        to_std_duration(t.sec, t.nsec)
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(d: std::time::Duration) -> Self {
+        Duration {
+            sec: d.as_secs() as i64,
+            nsec: d.subsec_nanos() as i32,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        to_std_duration(d.sec, d.nsec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_rpy() {
+        let q = Quaternion::from_rpy(0.0, 0.0, FRAC_PI_2);
+        assert_close(q.z, FRAC_PI_4.sin());
+        assert_close(q.w, FRAC_PI_4.cos());
+
+        let (roll, pitch, yaw) = (0.1, -0.4, 2.5);
+        let (r, p, y) = Quaternion::from_rpy(roll, pitch, yaw).to_rpy();
+        assert_close(r, roll);
+        assert_close(p, pitch);
+        assert_close(y, yaw);
+
+        let pose = Pose::from_xyz_rpy(1.0, 2.0, 3.0, 0.0, 0.0, 0.0);
+        assert_eq!(
+            <[f64; 3]>::from(pose.position_or_default()),
+            [1.0, 2.0, 3.0]
+        );
+        assert_eq!(pose.orientation_or_identity(), Quaternion::identity());
+        assert_eq!(
+            Pose::default().orientation_or_identity(),
+            Quaternion::identity()
+        );
+    }
+
+    #[test]
+    fn test_from() {
+        let twist = Twist::new([0.5, 0.0, 0.0], (0.0, 0.0, 0.1));
+        assert_eq!(twist.linear.unwrap().x, 0.5);
+        assert_eq!(twist.angular.unwrap().z, 0.1);
+        assert_eq!(
+            Color::from([1.0, 0.5, 0.0]),
+            Color::rgba(1.0, 0.5, 0.0, 1.0)
+        );
+        assert_eq!(
+            <[f64; 4]>::from(Quaternion::identity()),
+            [0.0, 0.0, 0.0, 1.0]
+        );
+
+        let d = std::time::Duration::new(3, 500);
+        let time = Time::from(d);
+        assert_eq!((time.sec, time.nsec), (3, 500));
+        assert_eq!(std::time::Duration::try_from(time).unwrap(), d);
+        assert_eq!(std::time::Duration::try_from(Duration::from(d)).unwrap(), d);
+        assert!(std::time::Duration::try_from(Time { sec: -1, nsec: 0 }).is_err());
+    }
+}
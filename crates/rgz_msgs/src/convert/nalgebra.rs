@@ -0,0 +1,103 @@
+use ::nalgebra::{Isometry3, Point3, Translation3, UnitQuaternion, Vector2, Vector3};
+
+use crate::{Pose, Quaternion, Vector2d, Vector3d};
+
+impl From<Vector2<f64>> for Vector2d {
+    fn from(v: Vector2<f64>) -> Self {
+        Vector2d::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2d> for Vector2<f64> {
+    fn from(v: Vector2d) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector3<f64>> for Vector3d {
+    fn from(v: Vector3<f64>) -> Self {
+        Vector3d::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3d> for Vector3<f64> {
+    fn from(v: Vector3d) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Point3<f64>> for Vector3d {
+    fn from(p: Point3<f64>) -> Self {
+        Vector3d::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vector3d> for Point3<f64> {
+    fn from(v: Vector3d) -> Self {
+        Point3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<::nalgebra::Quaternion<f64>> for Quaternion {
+    fn from(q: ::nalgebra::Quaternion<f64>) -> Self {
+        Quaternion::from_xyzw(q.i, q.j, q.k, q.w)
+    }
+}
+
+impl From<Quaternion> for ::nalgebra::Quaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        ::nalgebra::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<UnitQuaternion<f64>> for Quaternion {
+    fn from(q: UnitQuaternion<f64>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+impl From<Quaternion> for UnitQuaternion<f64> {
+    /// The quaternion is normalized.
+    fn from(q: Quaternion) -> Self {
+        UnitQuaternion::from_quaternion(q.into())
+    }
+}
+
+impl From<Isometry3<f64>> for Pose {
+    fn from(iso: Isometry3<f64>) -> Self {
+        Pose::new(iso.translation.vector, iso.rotation)
+    }
+}
+
+impl From<Pose> for Isometry3<f64> {
+    fn from(pose: Pose) -> Self {
+        let translation: Vector3<f64> = pose.position_or_default().into();
+        Isometry3::from_parts(
+            Translation3::from(translation),
+            pose.orientation_or_identity().into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra() {
+        let pose = Pose::from_xyz_rpy(1.0, 2.0, 3.0, 0.3, -0.2, 1.0);
+        let iso = Isometry3::from(pose.clone());
+        assert_eq!(iso.translation.vector, Vector3::new(1.0, 2.0, 3.0));
+        let (roll, pitch, yaw) = iso.rotation.euler_angles();
+        assert!(
+            (roll - 0.3).abs() < 1e-9 && (pitch + 0.2).abs() < 1e-9 && (yaw - 1.0).abs() < 1e-9
+        );
+        let back = Pose::from(iso);
+        assert_eq!(back.position, pose.position);
+        let q = UnitQuaternion::from(Quaternion::from_xyzw(0.0, 0.0, 2.0, 0.0));
+        assert_eq!(
+            Quaternion::from(q),
+            Quaternion::from_xyzw(0.0, 0.0, 1.0, 0.0)
+        );
+    }
+}
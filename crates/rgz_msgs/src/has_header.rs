@@ -0,0 +1,115 @@
+//! Access to the optional `header` of the messages.
+use std::time::Duration;
+
+use crate::{header, Header, Time};
+
+/// Key of the header data holding the coordinate frame, as in gz-sim.
+pub const FRAME_ID: &str = "frame_id";
+
+/// Messages with a `header` field, implemented by `#[derive(GzMessage)]`.
+pub trait HasHeader {
+    fn header(&self) -> Option<&Header>;
+
+    /// The header, inserted if unset.
+    fn header_mut(&mut self) -> &mut Header;
+}
+
+impl Header {
+    /// First value of the data entry with the given key.
+    pub fn data_value(&self, key: &str) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|map| map.key == key)
+            .and_then(|map| map.value.first())
+            .map(String::as_str)
+    }
+
+    /// Replace the values of the data entry with the given key, or add it.
+    pub fn set_data_value(&mut self, key: &str, value: &str) {
+        match self.data.iter_mut().find(|map| map.key == key) {
+            Some(map) => map.value = vec![value.to_string()],
+            None => self.data.push(header::Map {
+                key: key.to_string(),
+                value: vec![value.to_string()],
+            }),
+        }
+    }
+
+    pub fn frame_id(&self) -> Option<&str> {
+        self.data_value(FRAME_ID)
+    }
+
+    pub fn set_frame_id(&mut self, frame_id: &str) {
+        self.set_data_value(FRAME_ID, frame_id);
+    }
+
+    pub fn set_stamp(&mut self, stamp: Duration) {
+        self.stamp = Some(Time {
+            sec: stamp.as_secs() as i64,
+            nsec: stamp.subsec_nanos() as i32,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Empty, GzMessage, Pose, Vector3d};
+
+    #[test]
+    fn test_has_header() {
+        let mut pose = Pose::default();
+        assert!(pose.header().is_none());
+        pose.header_mut().set_frame_id("world");
+        pose.header_mut().set_frame_id("base_link");
+        pose.header_mut().set_stamp(Duration::new(2, 5));
+        let header = pose.header().unwrap();
+        assert_eq!(header.frame_id(), Some("base_link"));
+        assert_eq!(header.data.len(), 1);
+        assert_eq!(header.stamp, Some(Time { sec: 2, nsec: 5 }));
+
+        // Generic code reaches the header through `GzMessage`.
+        let mut v = Vector3d::default();
+        assert!(v.header_field_mut().is_some());
+        assert!(v.header.is_some());
+        assert!(Header::default().header_field_mut().is_none());
+        assert!(Empty::default().header_field_mut().is_none());
+    }
+
+    // A message with a `Header` of its own, which must not be taken for ours.
+    #[derive(Clone, PartialEq, prost::Message, GzMessage)]
+    #[gz(type_name = "my.pkg.Stamped")]
+    struct Stamped {
+        #[prost(message, optional, tag = "1")]
+        header: Option<my_pkg::Header>,
+        #[prost(message, optional, tag = "2")]
+        gz_header: Option<rgz_msgs::Header>,
+    }
+
+    mod my_pkg {
+        #[derive(Clone, PartialEq, prost::Message)]
+        pub struct Header {
+            #[prost(string, tag = "1")]
+            pub id: String,
+        }
+    }
+
+    #[derive(Clone, PartialEq, prost::Message, GzMessage)]
+    #[gz(type_name = "my.pkg.Marked")]
+    struct Marked {
+        #[prost(message, optional, tag = "1")]
+        #[gz(header)]
+        stamp: Option<Header>,
+    }
+
+    #[test]
+    fn test_header_path() {
+        let mut stamped = Stamped::default();
+        assert!(stamped.header_field_mut().is_none());
+        assert!(stamped.gz_header.is_none());
+
+        let mut marked = Marked::default();
+        marked.header_mut().set_frame_id("world");
+        assert_eq!(marked.stamp.unwrap().frame_id(), Some("world"));
+    }
+}
@@ -1,7 +1,125 @@
-#[path = "gz.msgs.rs"]
-mod msgs;
+// The derive refers to `::rgz_msgs`, also within this crate.
+extern crate self as rgz_msgs;
+
+mod msgs {
+    include!(concat!(env!("OUT_DIR"), "/gz.msgs.rs"));
+}
 pub use msgs::*;
 
+mod has_header;
+pub mod registry;
+#[cfg(feature = "text-format")]
+pub mod text_format;
+#[cfg(feature = "serde")]
+mod any_serde;
+#[cfg(feature = "build")]
+pub mod build;
+#[cfg(feature = "convert")]
+mod convert;
+#[cfg(feature = "sim-state")]
+pub mod sim_state;
+mod validate;
+pub mod view;
+
+pub use has_header::{HasHeader, FRAME_ID};
+pub use rgz_derive::GzMessage;
+pub use validate::{Validate, ValidationError};
+
 pub trait GzMessage: prost::Message {
     const TYPE_NAME: &'static str;
+
+    /// The header of the messages implementing `HasHeader`, inserted if unset,
+    /// for code generic over all the messages.
+    fn header_field_mut(&mut self) -> Option<&mut Header> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Full name and `TYPE_NAME` of every gz.msgs type, written by `build.rs`.
+    const TYPE_NAMES: &[(&str, &str)] =
+        include!(concat!(env!("OUT_DIR"), "/gz.msgs.type_names.rs"));
+
+    #[test]
+    fn test_type_name() {
+        let type_names: HashMap<&str, &str> = TYPE_NAMES.iter().copied().collect();
+        let mut count = 0;
+        for desc in registry::descriptor_pool().all_messages() {
+            if desc.package_name() != "gz.msgs" || desc.is_map_entry() {
+                continue;
+            }
+            assert_eq!(
+                type_names.get(desc.full_name()),
+                Some(&desc.full_name()),
+                "{}",
+                desc.full_name()
+            );
+            count += 1;
+        }
+        assert_eq!(count, TYPE_NAMES.len());
+
+        assert_eq!(Pose::TYPE_NAME, "gz.msgs.Pose");
+        assert_eq!(PoseV::TYPE_NAME, "gz.msgs.Pose_V");
+        assert_eq!(StringMsgV::TYPE_NAME, "gz.msgs.StringMsg_V");
+        assert_eq!(CmdVel2D::TYPE_NAME, "gz.msgs.CmdVel2D");
+        assert_eq!(Gui::TYPE_NAME, "gz.msgs.GUI");
+        assert_eq!(ImuSensor::TYPE_NAME, "gz.msgs.IMUSensor");
+        assert_eq!(DvlBeamState::TYPE_NAME, "gz.msgs.DVLBeamState");
+        assert_eq!(header::Map::TYPE_NAME, "gz.msgs.Header.Map");
+        assert_eq!(
+            discovery::Publisher::TYPE_NAME,
+            "gz.msgs.Discovery.Publisher"
+        );
+        assert_eq!(
+            discovery::publisher::MessagePublisher::TYPE_NAME,
+            "gz.msgs.Discovery.Publisher.MessagePublisher"
+        );
+        assert_eq!(
+            friction::torsional::Ode::TYPE_NAME,
+            "gz.msgs.Friction.Torsional.ODE"
+        );
+        assert_eq!(
+            fuel_metadata::Model::TYPE_NAME,
+            "gz.msgs.FuelMetadata.Model"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let pose: Pose =
+            serde_json::from_str(r#"{"name": "box", "position": {"x": 1.0}}"#).unwrap();
+        assert_eq!(pose.name, "box");
+        assert_eq!(pose.position.as_ref().unwrap().x, 1.0);
+        assert!(pose.orientation.is_none());
+        let json = serde_json::to_string(&pose).unwrap();
+        assert_eq!(serde_json::from_str::<Pose>(&json).unwrap(), pose);
+
+        let discovery = Discovery {
+            disc_contents: Some(discovery::DiscContents::Pub(discovery::Publisher {
+                topic: "/foo".to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&discovery).unwrap();
+        assert_eq!(serde_json::from_str::<Discovery>(&json).unwrap(), discovery);
+
+        let value = ParameterValue {
+            data: Some(prost_types::Any {
+                type_url: "gz.msgs.Boolean".to_string(),
+                value: vec![8, 1],
+            }),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ParameterValue>(&json).unwrap(),
+            value
+        );
+    }
 }
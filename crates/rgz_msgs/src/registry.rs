@@ -0,0 +1,101 @@
+//! Descriptors of the gz.msgs types, to handle messages whose type is only
+//! known at runtime, e.g. from discovery.
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+pub use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, ReflectMessage, Value};
+
+/// File descriptor set of the gz.msgs protos, written by `build.rs`.
+const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/gz.msgs.bin"));
+
+const PACKAGE: &str = "gz.msgs";
+
+pub fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(FILE_DESCRIPTOR_SET).expect("invalid gz.msgs descriptor set")
+    })
+}
+
+pub fn message_descriptor(type_name: &str) -> Option<MessageDescriptor> {
+    descriptor_pool().get_message_by_name(type_name)
+}
+
+/// Full names of all the gz.msgs message types, e.g. `gz.msgs.Pose_V`.
+pub fn type_names() -> Vec<String> {
+    descriptor_pool()
+        .all_messages()
+        .filter(|desc| desc.package_name() == PACKAGE && !desc.is_map_entry())
+        .map(|desc| desc.full_name().to_string())
+        .collect()
+}
+
+/// Decode a serialized message of the given type.
+pub fn decode_dynamic(type_name: &str, bytes: &[u8]) -> Result<DynamicMessage> {
+    let desc = message_descriptor(type_name)
+        .ok_or_else(|| anyhow!("Unknown message type [{}]", type_name))?;
+    Ok(DynamicMessage::decode(desc, bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::{discovery, Discovery, GzMessage, Pose, PoseV, Quaternion, Vector3d};
+
+    #[test]
+    fn test_type_names() {
+        let names = type_names();
+        assert!(names.len() > 200);
+        for name in [
+            Pose::TYPE_NAME,
+            PoseV::TYPE_NAME,
+            discovery::Publisher::TYPE_NAME,
+            discovery::publisher::MessagePublisher::TYPE_NAME,
+        ] {
+            assert!(names.iter().any(|n| n == name), "{}", name);
+        }
+        assert!(names.iter().all(|n| n.starts_with("gz.msgs.")));
+        assert!(message_descriptor("gz.msgs.Unknown").is_none());
+    }
+
+    #[test]
+    fn test_decode_dynamic() {
+        let pose = Pose {
+            name: "box".to_string(),
+            position: Some(Vector3d {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                ..Default::default()
+            }),
+            orientation: Some(Quaternion {
+                w: 1.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let msg = decode_dynamic(Pose::TYPE_NAME, &pose.encode_to_vec()).unwrap();
+        assert_eq!(msg.get_field_by_name("name").unwrap().as_str(), Some("box"));
+        let position = msg.get_field_by_name("position").unwrap();
+        let position = position.as_message().unwrap();
+        assert_eq!(position.get_field_by_name("y").unwrap().as_f64(), Some(2.0));
+        // Decoding back to the generated type gives the same message.
+        assert_eq!(msg.transcode_to::<Pose>().unwrap(), pose);
+
+        let discovery = Discovery {
+            version: 10,
+            disc_contents: Some(discovery::DiscContents::Pub(discovery::Publisher {
+                topic: "/foo".to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let msg = decode_dynamic(Discovery::TYPE_NAME, &discovery.encode_to_vec()).unwrap();
+        assert_eq!(msg.transcode_to::<Discovery>().unwrap(), discovery);
+
+        assert!(decode_dynamic("gz.msgs.Unknown", &[]).is_err());
+        assert!(decode_dynamic(Pose::TYPE_NAME, &[0xff]).is_err());
+    }
+}
@@ -0,0 +1,396 @@
+//! Decoding of the gz-sim entity-component state, published on
+//! `/world/<name>/state` as `SerializedStepMap`.
+//!
+//! The components are identified by the hash of their registered name, and
+//! serialized as the gz.msgs message of their type, or as text (`operator<<`)
+//! for the plain values like `ParentEntity`. Components not known here, or
+//! that can't be decoded, are kept as `Component::Unknown`.
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use prost::Message;
+
+use crate::{
+    DoubleV, Geometry, Material, Pose, SerializedComponent, SerializedState, SerializedStateMap,
+    SerializedStepMap, Vector3d,
+};
+
+/// Type id of a component registered with the given name, like
+/// `gz::sim::components::Factory` (64 bit FNV-1a).
+pub const fn component_type_id(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+const WORLD: u64 = component_type_id("gz_sim_components.World");
+const MODEL: u64 = component_type_id("gz_sim_components.Model");
+const LINK: u64 = component_type_id("gz_sim_components.Link");
+const JOINT: u64 = component_type_id("gz_sim_components.Joint");
+const VISUAL: u64 = component_type_id("gz_sim_components.Visual");
+const COLLISION: u64 = component_type_id("gz_sim_components.Collision");
+const SENSOR: u64 = component_type_id("gz_sim_components.Sensor");
+const NAME: u64 = component_type_id("gz_sim_components.Name");
+const PARENT_ENTITY: u64 = component_type_id("gz_sim_components.ParentEntity");
+const STATIC: u64 = component_type_id("gz_sim_components.Static");
+const POSE: u64 = component_type_id("gz_sim_components.Pose");
+const WORLD_POSE: u64 = component_type_id("gz_sim_components.WorldPose");
+const LINEAR_VELOCITY: u64 = component_type_id("gz_sim_components.LinearVelocity");
+const ANGULAR_VELOCITY: u64 = component_type_id("gz_sim_components.AngularVelocity");
+const WORLD_LINEAR_VELOCITY: u64 = component_type_id("gz_sim_components.WorldLinearVelocity");
+const WORLD_ANGULAR_VELOCITY: u64 = component_type_id("gz_sim_components.WorldAngularVelocity");
+const LINEAR_ACCELERATION: u64 = component_type_id("gz_sim_components.LinearAcceleration");
+const ANGULAR_ACCELERATION: u64 = component_type_id("gz_sim_components.AngularAcceleration");
+const JOINT_POSITION: u64 = component_type_id("gz_sim_components.JointPosition");
+const JOINT_VELOCITY: u64 = component_type_id("gz_sim_components.JointVelocity");
+const JOINT_FORCE: u64 = component_type_id("gz_sim_components.JointForce");
+const GEOMETRY: u64 = component_type_id("gz_sim_components.Geometry");
+const MATERIAL: u64 = component_type_id("gz_sim_components.Material");
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Component {
+    World,
+    Model,
+    Link,
+    Joint,
+    Visual,
+    Collision,
+    Sensor,
+    Name(String),
+    ParentEntity(u64),
+    Static(bool),
+    /// Pose relative to the parent entity.
+    Pose(Pose),
+    WorldPose(Pose),
+    LinearVelocity(Vector3d),
+    AngularVelocity(Vector3d),
+    WorldLinearVelocity(Vector3d),
+    WorldAngularVelocity(Vector3d),
+    LinearAcceleration(Vector3d),
+    AngularAcceleration(Vector3d),
+    JointPosition(Vec<f64>),
+    JointVelocity(Vec<f64>),
+    JointForce(Vec<f64>),
+    Geometry(Box<Geometry>),
+    Material(Box<Material>),
+    /// A component not known here, or whose data could not be decoded.
+    Unknown {
+        type_id: u64,
+        data: Vec<u8>,
+    },
+}
+
+fn parse_text<T: std::str::FromStr>(data: &[u8]) -> Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(std::str::from_utf8(data)?.trim().parse()?)
+}
+
+fn parse_bool(data: &[u8]) -> Result<bool> {
+    match std::str::from_utf8(data)?.trim() {
+        "1" | "true" => Ok(true),
+        "0" | "false" => Ok(false),
+        value => bail!("Invalid boolean [{}]", value),
+    }
+}
+
+impl Component {
+    /// Decode the serialized data of a component.
+    pub fn decode(type_id: u64, data: &[u8]) -> Result<Component> {
+        let component = match type_id {
+            WORLD => Component::World,
+            MODEL => Component::Model,
+            LINK => Component::Link,
+            JOINT => Component::Joint,
+            VISUAL => Component::Visual,
+            COLLISION => Component::Collision,
+            SENSOR => Component::Sensor,
+            NAME => Component::Name(String::from_utf8(data.to_vec())?),
+            PARENT_ENTITY => Component::ParentEntity(parse_text(data)?),
+            STATIC => Component::Static(parse_bool(data)?),
+            POSE => Component::Pose(Pose::decode(data)?),
+            WORLD_POSE => Component::WorldPose(Pose::decode(data)?),
+            LINEAR_VELOCITY => Component::LinearVelocity(Vector3d::decode(data)?),
+            ANGULAR_VELOCITY => Component::AngularVelocity(Vector3d::decode(data)?),
+            WORLD_LINEAR_VELOCITY => Component::WorldLinearVelocity(Vector3d::decode(data)?),
+            WORLD_ANGULAR_VELOCITY => Component::WorldAngularVelocity(Vector3d::decode(data)?),
+            LINEAR_ACCELERATION => Component::LinearAcceleration(Vector3d::decode(data)?),
+            ANGULAR_ACCELERATION => Component::AngularAcceleration(Vector3d::decode(data)?),
+            JOINT_POSITION => Component::JointPosition(DoubleV::decode(data)?.data),
+            JOINT_VELOCITY => Component::JointVelocity(DoubleV::decode(data)?.data),
+            JOINT_FORCE => Component::JointForce(DoubleV::decode(data)?.data),
+            GEOMETRY => Component::Geometry(Box::new(Geometry::decode(data)?)),
+            MATERIAL => Component::Material(Box::new(Material::decode(data)?)),
+            _ => Component::Unknown {
+                type_id,
+                data: data.to_vec(),
+            },
+        };
+        Ok(component)
+    }
+}
+
+/// An entity and its components, by type id.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Entity {
+    pub id: u64,
+    pub components: BTreeMap<u64, Component>,
+}
+
+impl Entity {
+    pub fn name(&self) -> Option<&str> {
+        match self.components.get(&NAME) {
+            Some(Component::Name(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    pub fn parent(&self) -> Option<u64> {
+        match self.components.get(&PARENT_ENTITY) {
+            Some(Component::ParentEntity(parent)) => Some(*parent),
+            _ => None,
+        }
+    }
+
+    pub fn pose(&self) -> Option<&Pose> {
+        match self.components.get(&POSE) {
+            Some(Component::Pose(pose)) => Some(pose),
+            _ => None,
+        }
+    }
+}
+
+/// The entities of a world, updated with the state messages.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EcsState {
+    entities: BTreeMap<u64, Entity>,
+}
+
+impl EcsState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entity(&self, id: u64) -> Option<&Entity> {
+        self.entities.get(&id)
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.values()
+    }
+
+    /// Children of an entity, from their `ParentEntity` component.
+    pub fn children(&self, parent: u64) -> impl Iterator<Item = &Entity> {
+        self.entities()
+            .filter(move |entity| entity.parent() == Some(parent))
+    }
+
+    fn apply_entity<'a>(
+        &mut self,
+        id: u64,
+        remove: bool,
+        components: impl Iterator<Item = &'a SerializedComponent>,
+    ) {
+        if remove {
+            self.entities.remove(&id);
+            return;
+        }
+        let entity = self.entities.entry(id).or_insert_with(|| Entity {
+            id,
+            ..Default::default()
+        });
+        for component in components {
+            if component.remove {
+                entity.components.remove(&component.r#type);
+                continue;
+            }
+            // One component of a newer gz-sim must not discard the others.
+            let decoded = match Component::decode(component.r#type, &component.component) {
+                Ok(decoded) => decoded,
+                Err(_) => Component::Unknown {
+                    type_id: component.r#type,
+                    data: component.component.clone(),
+                },
+            };
+            entity.components.insert(component.r#type, decoded);
+        }
+    }
+
+    /// Apply a state, the entities of the previous states are kept.
+    pub fn apply_state_map(&mut self, state: &SerializedStateMap) {
+        for entity in state.entities.values() {
+            self.apply_entity(entity.id, entity.remove, entity.components.values());
+        }
+    }
+
+    pub fn apply_state(&mut self, state: &SerializedState) {
+        for entity in &state.entities {
+            self.apply_entity(entity.id, entity.remove, entity.components.iter());
+        }
+    }
+
+    pub fn apply_step_map(&mut self, step: &SerializedStepMap) {
+        if let Some(state) = &step.state {
+            self.apply_state_map(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quaternion, SerializedEntity, SerializedEntityMap};
+
+    // gz.msgs.Pose of a model at z = 1 then 2, as written by the Pose serializer.
+    const POSE_Z1: &[u8] = &[
+        0x22, 0x09, 0x21, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, // position { z: 1 }
+        0x2a, 0x09, 0x29, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, // orientation { w: 1 }
+    ];
+    const POSE_Z2: &[u8] = &[
+        0x22, 0x09, 0x21, 0, 0, 0, 0, 0, 0, 0, 0x40, // position { z: 2 }
+        0x2a, 0x09, 0x29, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, // orientation { w: 1 }
+    ];
+    // gz.msgs.Vector3d { x: 1, z: -0.5 }.
+    const VELOCITY: &[u8] = &[
+        0x11, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, // x: 1
+        0x21, 0, 0, 0, 0, 0, 0, 0xe0, 0xbf, // z: -0.5
+    ];
+
+    fn component(name: &str, data: &[u8]) -> SerializedComponent {
+        SerializedComponent {
+            r#type: component_type_id(name),
+            component: data.to_vec(),
+            remove: false,
+        }
+    }
+
+    #[test]
+    fn test_component_type_id() {
+        assert_eq!(component_type_id(""), 0xcbf29ce484222325);
+        assert_eq!(component_type_id("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            Component::decode(NAME, b"box").unwrap(),
+            Component::Name("box".to_string())
+        );
+        assert_eq!(
+            Component::decode(PARENT_ENTITY, b"12").unwrap(),
+            Component::ParentEntity(12)
+        );
+        assert_eq!(
+            Component::decode(STATIC, b"1").unwrap(),
+            Component::Static(true)
+        );
+        assert_eq!(
+            Component::decode(LINEAR_VELOCITY, VELOCITY).unwrap(),
+            Component::LinearVelocity(Vector3d::new(1.0, 0.0, -0.5))
+        );
+        assert_eq!(
+            Component::decode(POSE, POSE_Z1).unwrap(),
+            Component::Pose(Pose::new([0.0, 0.0, 1.0], Quaternion::identity()))
+        );
+        let positions = DoubleV {
+            data: vec![0.1, 0.2],
+        };
+        assert_eq!(
+            Component::decode(JOINT_POSITION, &positions.encode_to_vec()).unwrap(),
+            Component::JointPosition(vec![0.1, 0.2])
+        );
+        assert!(Component::decode(POSE, &POSE_Z1[..5]).is_err());
+        assert!(matches!(
+            Component::decode(1, b"?").unwrap(),
+            Component::Unknown { type_id: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_ecs_state() {
+        let mut state = EcsState::new();
+        let model = SerializedEntityMap {
+            id: 2,
+            components: [
+                component("gz_sim_components.Model", b""),
+                component("gz_sim_components.Name", b"robot"),
+                component("gz_sim_components.ParentEntity", b"1"),
+                component("gz_sim_components.Pose", POSE_Z1),
+                component("gz_sim_components.LinearVelocity", VELOCITY),
+            ]
+            .into_iter()
+            .map(|c| (c.r#type as i64, c))
+            .collect(),
+            remove: false,
+        };
+        let step = SerializedStepMap {
+            state: Some(SerializedStateMap {
+                entities: [(2, model)].into_iter().collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        state.apply_step_map(&step);
+        let robot = state.entity(2).unwrap();
+        assert_eq!(robot.name(), Some("robot"));
+        assert_eq!(robot.components.get(&MODEL), Some(&Component::Model));
+        assert_eq!(robot.pose().unwrap().position_or_default().z, 1.0);
+        assert_eq!(
+            robot.components.get(&LINEAR_VELOCITY),
+            Some(&Component::LinearVelocity(Vector3d::new(1.0, 0.0, -0.5)))
+        );
+        assert_eq!(state.children(1).count(), 1);
+
+        // Updates keep the other components, and a component that can't be
+        // decoded doesn't stop the others.
+        let mut pose = component("gz_sim_components.Pose", POSE_Z2);
+        let invalid = component("gz_sim_components.WorldPose", &POSE_Z1[..5]);
+        state.apply_state(&SerializedState {
+            entities: vec![SerializedEntity {
+                id: 2,
+                components: vec![invalid.clone(), pose.clone()],
+                remove: false,
+            }],
+            ..Default::default()
+        });
+        let robot = state.entity(2).unwrap();
+        assert_eq!(robot.pose().unwrap().position_or_default().z, 2.0);
+        assert_eq!(robot.name(), Some("robot"));
+        assert_eq!(
+            robot.components.get(&WORLD_POSE),
+            Some(&Component::Unknown {
+                type_id: WORLD_POSE,
+                data: invalid.component,
+            })
+        );
+
+        pose.remove = true;
+        let removal = SerializedState {
+            entities: vec![SerializedEntity {
+                id: 2,
+                components: vec![pose],
+                remove: false,
+            }],
+            ..Default::default()
+        };
+        state.apply_state(&removal);
+        assert!(state.entity(2).unwrap().pose().is_none());
+
+        let removal = SerializedState {
+            entities: vec![SerializedEntity {
+                id: 2,
+                remove: true,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        state.apply_state(&removal);
+        assert!(state.entity(2).is_none());
+    }
+}
@@ -0,0 +1,60 @@
+//! Protobuf text format, as printed by `gz topic -e`.
+use anyhow::{anyhow, Result};
+use prost_reflect::DynamicMessage;
+
+use crate::registry::message_descriptor;
+use crate::GzMessage;
+
+/// Print a message in text format, e.g. `name:"box" position{x:1}`.
+pub fn to_string<T: GzMessage>(msg: &T) -> Result<String> {
+    Ok(to_dynamic(msg)?.to_text_format())
+}
+
+/// Print a message in text format, one field per line.
+pub fn to_string_pretty<T: GzMessage>(msg: &T) -> Result<String> {
+    let options = prost_reflect::text_format::FormatOptions::new().pretty(true);
+    Ok(to_dynamic(msg)?.to_text_format_with_options(&options))
+}
+
+/// Parse a message in text format.
+pub fn from_str<T: GzMessage + Default>(text: &str) -> Result<T> {
+    let desc = message_descriptor(T::TYPE_NAME)
+        .ok_or_else(|| anyhow!("Unknown message type [{}]", T::TYPE_NAME))?;
+    let msg = DynamicMessage::parse_text_format(desc, text)?;
+    Ok(msg.transcode_to::<T>()?)
+}
+
+fn to_dynamic<T: GzMessage>(msg: &T) -> Result<DynamicMessage> {
+    let desc = message_descriptor(T::TYPE_NAME)
+        .ok_or_else(|| anyhow!("Unknown message type [{}]", T::TYPE_NAME))?;
+    let bytes = msg.encode_to_vec();
+    Ok(DynamicMessage::decode(desc, bytes.as_slice())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pose, Vector3d};
+
+    #[test]
+    fn test_text_format() {
+        let pose = Pose {
+            name: "box".to_string(),
+            position: Some(Vector3d {
+                x: 1.0,
+                z: -2.5,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let text = to_string(&pose).unwrap();
+        assert_eq!(text, r#"name:"box",position{x:1.0,z:-2.5}"#);
+        assert_eq!(from_str::<Pose>(&text).unwrap(), pose);
+        assert!(to_string_pretty(&pose).unwrap().contains("position {\n"));
+
+        let pose: Pose = from_str("name: 'cmd' orientation { w: 1 }").unwrap();
+        assert_eq!(pose.name, "cmd");
+        assert_eq!(pose.orientation.unwrap().w, 1.0);
+        assert!(from_str::<Pose>("unknown_field: 1").is_err());
+    }
+}
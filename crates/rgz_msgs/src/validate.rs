@@ -0,0 +1,397 @@
+//! Sanity checks of received messages, before using their fields.
+use std::fmt;
+
+use crate::point_cloud_packed::field::DataType;
+use crate::{Image, LaserScan, PixelFormatType, PointCloudPacked, Pose, PoseV, Quaternion};
+
+/// Tolerance on the norm of the quaternions.
+const NORM_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// A required message field is unset.
+    MissingField(&'static str),
+    /// A field is NaN or infinite.
+    NotFinite(&'static str),
+    /// A quaternion is not a rotation.
+    NotNormalized { field: &'static str, norm: f64 },
+    /// An enum field has an unknown value.
+    InvalidEnum { field: &'static str, value: i32 },
+    /// A length does not match the one given by the other fields.
+    LengthMismatch {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// A point cloud field does not fit in `point_step` or overlaps another field.
+    InvalidFieldLayout { field: String, reason: &'static str },
+    /// An element of a repeated field is invalid.
+    Element {
+        index: usize,
+        error: Box<ValidationError>,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::MissingField(field) => write!(f, "Missing field [{}]", field),
+            ValidationError::NotFinite(field) => write!(f, "Field [{}] is not finite", field),
+            ValidationError::NotNormalized { field, norm } => {
+                write!(f, "Quaternion [{}] is not normalized, norm {}", field, norm)
+            }
+            ValidationError::InvalidEnum { field, value } => {
+                write!(f, "Invalid value [{}] of field [{}]", value, field)
+            }
+            ValidationError::LengthMismatch {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Field [{}] has length {}, expected {}",
+                field, actual, expected
+            ),
+            ValidationError::InvalidFieldLayout { field, reason } => {
+                write!(f, "Point field [{}] {}", field, reason)
+            }
+            ValidationError::Element { index, error } => write!(f, "Element {}: {}", index, error),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Messages that can be checked before processing.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+fn check_finite(field: &'static str, values: &[f64]) -> Result<(), ValidationError> {
+    if values.iter().all(|v| v.is_finite()) {
+        Ok(())
+    } else {
+        Err(ValidationError::NotFinite(field))
+    }
+}
+
+fn check_len(field: &'static str, expected: usize, actual: usize) -> Result<(), ValidationError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ValidationError::LengthMismatch {
+            field,
+            expected,
+            actual,
+        })
+    }
+}
+
+impl Validate for Quaternion {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_finite("orientation", &[self.x, self.y, self.z, self.w])?;
+        let norm = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if (norm - 1.0).abs() > NORM_TOLERANCE {
+            return Err(ValidationError::NotNormalized {
+                field: "orientation",
+                norm,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Validate for Pose {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let position = self
+            .position
+            .as_ref()
+            .ok_or(ValidationError::MissingField("position"))?;
+        check_finite("position", &[position.x, position.y, position.z])?;
+        self.orientation
+            .as_ref()
+            .ok_or(ValidationError::MissingField("orientation"))?
+            .validate()
+    }
+}
+
+impl Validate for PoseV {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (index, pose) in self.pose.iter().enumerate() {
+            pose.validate().map_err(|error| ValidationError::Element {
+                index,
+                error: Box::new(error),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl PixelFormatType {
+    /// Size of a pixel, `None` for an unknown format.
+    pub fn bytes_per_pixel(&self) -> Option<u32> {
+        match self {
+            PixelFormatType::UnknownPixelFormat => None,
+            PixelFormatType::LInt8
+            | PixelFormatType::BayerRggb8
+            | PixelFormatType::BayerBggr8
+            | PixelFormatType::BayerGbrg8
+            | PixelFormatType::BayerGrbg8 => Some(1),
+            PixelFormatType::LInt16 | PixelFormatType::RFloat16 => Some(2),
+            PixelFormatType::RgbInt8 | PixelFormatType::BgrInt8 => Some(3),
+            PixelFormatType::RgbaInt8 | PixelFormatType::BgraInt8 | PixelFormatType::RFloat32 => {
+                Some(4)
+            }
+            PixelFormatType::RgbInt16 | PixelFormatType::BgrInt16 | PixelFormatType::RgbFloat16 => {
+                Some(6)
+            }
+            PixelFormatType::RgbInt32 | PixelFormatType::BgrInt32 | PixelFormatType::RgbFloat32 => {
+                Some(12)
+            }
+        }
+    }
+}
+
+impl Validate for Image {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let format = PixelFormatType::try_from(self.pixel_format_type).map_err(|_| {
+            ValidationError::InvalidEnum {
+                field: "pixel_format_type",
+                value: self.pixel_format_type,
+            }
+        })?;
+        // The rows may be padded, but must hold the pixels.
+        if let Some(bpp) = format.bytes_per_pixel() {
+            let row_len = self.width as usize * bpp as usize;
+            if (self.step as usize) < row_len {
+                return Err(ValidationError::LengthMismatch {
+                    field: "step",
+                    expected: row_len,
+                    actual: self.step as usize,
+                });
+            }
+        }
+        check_len(
+            "data",
+            self.height as usize * self.step as usize,
+            self.data.len(),
+        )
+    }
+}
+
+impl DataType {
+    pub fn size(&self) -> u32 {
+        match self {
+            DataType::Int8 | DataType::Uint8 => 1,
+            DataType::Int16 | DataType::Uint16 => 2,
+            DataType::Int32 | DataType::Uint32 | DataType::Float32 => 4,
+            DataType::Float64 => 8,
+        }
+    }
+}
+
+impl Validate for PointCloudPacked {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut ranges = Vec::with_capacity(self.field.len());
+        for field in &self.field {
+            let datatype =
+                DataType::try_from(field.datatype).map_err(|_| ValidationError::InvalidEnum {
+                    field: "datatype",
+                    value: field.datatype,
+                })?;
+            let end = field.offset as u64 + datatype.size() as u64 * field.count.max(1) as u64;
+            if end > self.point_step as u64 {
+                return Err(ValidationError::InvalidFieldLayout {
+                    field: field.name.clone(),
+                    reason: "exceeds point_step",
+                });
+            }
+            ranges.push((field.offset as u64, end, &field.name));
+        }
+        ranges.sort();
+        for pair in ranges.windows(2) {
+            if pair[1].0 < pair[0].1 {
+                return Err(ValidationError::InvalidFieldLayout {
+                    field: pair[1].2.clone(),
+                    reason: "overlaps another field",
+                });
+            }
+        }
+
+        let row_len = self.width as usize * self.point_step as usize;
+        if (self.row_step as usize) < row_len {
+            return Err(ValidationError::LengthMismatch {
+                field: "row_step",
+                expected: row_len,
+                actual: self.row_step as usize,
+            });
+        }
+        check_len(
+            "data",
+            self.height as usize * self.row_step as usize,
+            self.data.len(),
+        )
+    }
+}
+
+impl Validate for LaserScan {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_finite("angle", &[self.angle_min, self.angle_max, self.angle_step])?;
+        // 2D scans may leave vertical_count unset.
+        let expected = self.count as usize * self.vertical_count.max(1) as usize;
+        check_len("ranges", expected, self.ranges.len())?;
+        if !self.intensities.is_empty() {
+            check_len("intensities", expected, self.intensities.len())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point_cloud_packed, Vector3d};
+
+    fn pose() -> Pose {
+        Pose {
+            position: Some(Vector3d::default()),
+            orientation: Some(Quaternion {
+                w: 1.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pose() {
+        assert_eq!(pose().validate(), Ok(()));
+        assert_eq!(
+            Pose::default().validate(),
+            Err(ValidationError::MissingField("position"))
+        );
+
+        let mut invalid = pose();
+        invalid.orientation.as_mut().unwrap().w = 2.0;
+        assert_eq!(
+            invalid.validate(),
+            Err(ValidationError::NotNormalized {
+                field: "orientation",
+                norm: 2.0
+            })
+        );
+        invalid.position.as_mut().unwrap().x = f64::NAN;
+        assert_eq!(
+            invalid.validate(),
+            Err(ValidationError::NotFinite("position"))
+        );
+
+        let poses = PoseV {
+            pose: vec![pose(), Pose::default()],
+            ..Default::default()
+        };
+        let err = poses.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Element 1: Missing field [position]");
+    }
+
+    #[test]
+    fn test_image() {
+        let mut image = Image {
+            width: 2,
+            height: 2,
+            step: 6,
+            data: vec![0; 12],
+            pixel_format_type: PixelFormatType::RgbInt8 as i32,
+            ..Default::default()
+        };
+        assert_eq!(image.validate(), Ok(()));
+        image.data.pop();
+        assert_eq!(
+            image.validate(),
+            Err(ValidationError::LengthMismatch {
+                field: "data",
+                expected: 12,
+                actual: 11
+            })
+        );
+        image.step = 4;
+        assert!(matches!(
+            image.validate(),
+            Err(ValidationError::LengthMismatch { field: "step", .. })
+        ));
+        image.pixel_format_type = 100;
+        assert!(matches!(
+            image.validate(),
+            Err(ValidationError::InvalidEnum { .. })
+        ));
+    }
+
+    #[test]
+    fn test_point_cloud_packed() {
+        let field = |name: &str, offset| point_cloud_packed::Field {
+            name: name.to_string(),
+            offset,
+            datatype: DataType::Float32 as i32,
+            count: 1,
+        };
+        let mut cloud = PointCloudPacked {
+            field: vec![field("x", 0), field("y", 4), field("z", 8)],
+            height: 1,
+            width: 2,
+            point_step: 12,
+            row_step: 24,
+            data: vec![0; 24],
+            ..Default::default()
+        };
+        assert_eq!(cloud.validate(), Ok(()));
+
+        cloud.field[2].offset = 6;
+        assert_eq!(
+            cloud.validate(),
+            Err(ValidationError::InvalidFieldLayout {
+                field: "z".to_string(),
+                reason: "overlaps another field"
+            })
+        );
+        cloud.field[2].offset = 10;
+        assert!(matches!(
+            cloud.validate(),
+            Err(ValidationError::InvalidFieldLayout {
+                reason: "exceeds point_step",
+                ..
+            })
+        ));
+        cloud.field[2].offset = 8;
+        cloud.row_step = 12;
+        assert!(matches!(
+            cloud.validate(),
+            Err(ValidationError::LengthMismatch {
+                field: "row_step",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_laser_scan() {
+        let mut scan = LaserScan {
+            count: 3,
+            vertical_count: 1,
+            ranges: vec![1.0; 3],
+            ..Default::default()
+        };
+        assert_eq!(scan.validate(), Ok(()));
+        scan.intensities = vec![0.0; 2];
+        assert!(scan.validate().is_err());
+        scan.intensities.clear();
+        scan.vertical_count = 2;
+        assert_eq!(
+            scan.validate(),
+            Err(ValidationError::LengthMismatch {
+                field: "ranges",
+                expected: 6,
+                actual: 3
+            })
+        );
+    }
+}
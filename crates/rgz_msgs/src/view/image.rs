@@ -0,0 +1,255 @@
+use std::marker::PhantomData;
+
+use anyhow::{bail, Result};
+
+use crate::{Image, PixelFormatType, Validate};
+
+// Little endian sample of a pixel, gz-sensors writes the images in host order.
+trait Channel: Copy {
+    const SIZE: usize;
+    fn read(bytes: &[u8]) -> Self;
+    fn write(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_channel {
+    ($($ty:ty),*) => {$(
+        impl Channel for $ty {
+            const SIZE: usize = std::mem::size_of::<$ty>();
+
+            fn read(bytes: &[u8]) -> Self {
+                <$ty>::from_le_bytes(bytes[..std::mem::size_of::<$ty>()].try_into().unwrap())
+            }
+
+            fn write(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_channel!(u8, u16, u32, f32);
+
+/// Pixel type of an `ImageView`, with the channels in the order of the format,
+/// e.g. `[u8; 3]` is `[b, g, r]` for `BGR_INT8`.
+pub trait Pixel: Copy + 'static {
+    /// The formats stored as this type.
+    const FORMATS: &'static [PixelFormatType];
+    const SIZE: usize;
+
+    fn read(bytes: &[u8]) -> Self;
+    fn write(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_pixel {
+    ($ty:ty, [$($format:ident),*]) => {
+        impl Pixel for $ty {
+            const FORMATS: &'static [PixelFormatType] = &[$(PixelFormatType::$format),*];
+            const SIZE: usize = <$ty as Channel>::SIZE;
+
+            fn read(bytes: &[u8]) -> Self {
+                Channel::read(bytes)
+            }
+
+            fn write(self, out: &mut Vec<u8>) {
+                Channel::write(self, out)
+            }
+        }
+    };
+    ($ty:ty; $n:literal, [$($format:ident),*]) => {
+        impl Pixel for [$ty; $n] {
+            const FORMATS: &'static [PixelFormatType] = &[$(PixelFormatType::$format),*];
+            const SIZE: usize = <$ty as Channel>::SIZE * $n;
+
+            fn read(bytes: &[u8]) -> Self {
+                std::array::from_fn(|i| Channel::read(&bytes[i * <$ty as Channel>::SIZE..]))
+            }
+
+            fn write(self, out: &mut Vec<u8>) {
+                for channel in self {
+                    Channel::write(channel, out);
+                }
+            }
+        }
+    };
+}
+
+impl_pixel!(u8, [LInt8, BayerRggb8, BayerBggr8, BayerGbrg8, BayerGrbg8]);
+impl_pixel!(u16, [LInt16]);
+// Depth images.
+impl_pixel!(f32, [RFloat32]);
+impl_pixel!(u8; 3, [RgbInt8, BgrInt8]);
+impl_pixel!(u8; 4, [RgbaInt8, BgraInt8]);
+impl_pixel!(u16; 3, [RgbInt16, BgrInt16]);
+impl_pixel!(u32; 3, [RgbInt32, BgrInt32]);
+impl_pixel!(f32; 3, [RgbFloat32]);
+
+/// Pixels of an `Image`, by row and column.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a, P> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    step: usize,
+    _pixel: PhantomData<P>,
+}
+
+impl<'a, P: Pixel> ImageView<'a, P> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<P> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let start = y * self.step + x * P::SIZE;
+        Some(P::read(&self.data[start..start + P::SIZE]))
+    }
+
+    /// Pixels of a row, without the padding. Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = P> + 'a {
+        assert!(y < self.height, "row {} out of bounds", y);
+        let start = y * self.step;
+        self.data[start..start + self.width * P::SIZE]
+            .chunks_exact(P::SIZE)
+            .map(P::read)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = P> + 'a> + 'a {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    /// All the pixels, row by row.
+    pub fn pixels(&self) -> impl Iterator<Item = P> + 'a {
+        self.rows().flatten()
+    }
+
+    pub fn to_vec(&self) -> Vec<P> {
+        self.pixels().collect()
+    }
+}
+
+fn pixel_format(image: &Image) -> PixelFormatType {
+    PixelFormatType::try_from(image.pixel_format_type).unwrap_or_default()
+}
+
+impl Image {
+    /// View the pixels as `P`, which must match the pixel format.
+    pub fn view<P: Pixel>(&self) -> Result<ImageView<'_, P>> {
+        self.validate()?;
+        let format = pixel_format(self);
+        if !P::FORMATS.contains(&format) {
+            bail!(
+                "Pixel format [{}] is not stored as {}",
+                format.as_str_name(),
+                std::any::type_name::<P>()
+            );
+        }
+        Ok(ImageView {
+            data: &self.data,
+            width: self.width as usize,
+            height: self.height as usize,
+            step: self.step as usize,
+            _pixel: PhantomData,
+        })
+    }
+
+    /// Pack the pixels, given row by row, into an image without padding.
+    pub fn from_pixels<P: Pixel>(
+        width: u32,
+        height: u32,
+        format: PixelFormatType,
+        pixels: &[P],
+    ) -> Result<Image> {
+        if !P::FORMATS.contains(&format) {
+            bail!(
+                "Pixel format [{}] is not stored as {}",
+                format.as_str_name(),
+                std::any::type_name::<P>()
+            );
+        }
+        if pixels.len() != width as usize * height as usize {
+            bail!(
+                "Got {} pixels for a {}x{} image",
+                pixels.len(),
+                width,
+                height
+            );
+        }
+        let mut data = Vec::with_capacity(pixels.len() * P::SIZE);
+        for pixel in pixels {
+            pixel.write(&mut data);
+        }
+        Ok(Image {
+            width,
+            height,
+            step: width * P::SIZE as u32,
+            data,
+            pixel_format_type: format as i32,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_size<P: Pixel>() {
+        for format in P::FORMATS {
+            assert_eq!(
+                format.bytes_per_pixel(),
+                Some(P::SIZE as u32),
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_pixel_size() {
+        check_size::<u8>();
+        check_size::<u16>();
+        check_size::<f32>();
+        check_size::<[u8; 3]>();
+        check_size::<[u8; 4]>();
+        check_size::<[u16; 3]>();
+        check_size::<[u32; 3]>();
+        check_size::<[f32; 3]>();
+    }
+
+    #[test]
+    fn test_image_view() {
+        let pixels = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]];
+        let image = Image::from_pixels(2, 2, PixelFormatType::RgbInt8, &pixels).unwrap();
+        assert_eq!(image.step, 6);
+        let view = image.view::<[u8; 3]>().unwrap();
+        assert_eq!(view.get(1, 0), Some([4, 5, 6]));
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(
+            view.row(1).collect::<Vec<_>>(),
+            vec![[7, 8, 9], [10, 11, 12]]
+        );
+        assert_eq!(view.to_vec(), pixels);
+        assert!(image.view::<u8>().is_err());
+
+        // Rows padded to 4 bytes.
+        let depth = Image {
+            width: 1,
+            height: 2,
+            step: 8,
+            data: [1.5f32.to_le_bytes(), [0; 4], 2.5f32.to_le_bytes(), [0; 4]].concat(),
+            pixel_format_type: PixelFormatType::RFloat32 as i32,
+            ..Default::default()
+        };
+        assert_eq!(depth.view::<f32>().unwrap().to_vec(), vec![1.5, 2.5]);
+
+        assert!(Image::from_pixels(2, 2, PixelFormatType::LInt8, &[0u8; 3]).is_err());
+        assert!(Image::from_pixels(1, 1, PixelFormatType::LInt16, &[0u8]).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Typed access to the payloads of `Image` and `PointCloudPacked`, and
+//! builders packing them back.
+mod image;
+mod point_cloud;
+
+pub use image::{ImageView, Pixel};
+pub use point_cloud::{pack_rgb, unpack_rgb, FieldIter, PointCloudBuilder, PointField};
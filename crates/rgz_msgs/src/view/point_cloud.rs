@@ -0,0 +1,296 @@
+use std::marker::PhantomData;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::point_cloud_packed::{field::DataType, Field};
+use crate::{PointCloudPacked, Validate};
+
+/// Value type of a point cloud field.
+pub trait PointField: Copy {
+    const DATATYPE: DataType;
+
+    fn read(bytes: &[u8], big_endian: bool) -> Self;
+    fn write(self, out: &mut [u8], big_endian: bool);
+}
+
+macro_rules! impl_point_field {
+    ($($ty:ty => $datatype:ident),*) => {$(
+        impl PointField for $ty {
+            const DATATYPE: DataType = DataType::$datatype;
+
+            fn read(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes[..std::mem::size_of::<$ty>()].try_into().unwrap();
+                if big_endian {
+                    <$ty>::from_be_bytes(bytes)
+                } else {
+                    <$ty>::from_le_bytes(bytes)
+                }
+            }
+
+            fn write(self, out: &mut [u8], big_endian: bool) {
+                let bytes = if big_endian {
+                    self.to_be_bytes()
+                } else {
+                    self.to_le_bytes()
+                };
+                out[..bytes.len()].copy_from_slice(&bytes);
+            }
+        }
+    )*};
+}
+
+impl_point_field!(
+    i8 => Int8,
+    u8 => Uint8,
+    i16 => Int16,
+    u16 => Uint16,
+    i32 => Int32,
+    u32 => Uint32,
+    f32 => Float32,
+    f64 => Float64
+);
+
+/// Color packed in a FLOAT32 `rgb` field, as done by PCL and gz-sensors.
+pub fn pack_rgb([r, g, b]: [u8; 3]) -> f32 {
+    f32::from_bits(u32::from_be_bytes([0, r, g, b]))
+}
+
+pub fn unpack_rgb(rgb: f32) -> [u8; 3] {
+    let [_, r, g, b] = rgb.to_bits().to_be_bytes();
+    [r, g, b]
+}
+
+/// Values of a field, point by point and row by row.
+#[derive(Debug, Clone)]
+pub struct FieldIter<'a, T> {
+    cloud: &'a PointCloudPacked,
+    offset: usize,
+    index: usize,
+    len: usize,
+    _value: PhantomData<T>,
+}
+
+impl<'a, T> FieldIter<'a, T> {
+    // The layout must have been validated.
+    fn new(cloud: &'a PointCloudPacked, field: &Field) -> Self {
+        FieldIter {
+            cloud,
+            offset: field.offset as usize,
+            index: 0,
+            len: cloud.width as usize * cloud.height as usize,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: PointField> Iterator for FieldIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index >= self.len {
+            return None;
+        }
+        let width = self.cloud.width as usize;
+        let (row, col) = (self.index / width, self.index % width);
+        let start =
+            row * self.cloud.row_step as usize + col * self.cloud.point_step as usize + self.offset;
+        self.index += 1;
+        Some(T::read(&self.cloud.data[start..], self.cloud.is_bigendian))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: PointField> ExactSizeIterator for FieldIter<'a, T> {}
+
+impl PointCloudPacked {
+    fn find_field(&self, name: &str) -> Result<&Field> {
+        self.validate()?;
+        self.field
+            .iter()
+            .find(|field| field.name == name)
+            .ok_or_else(|| anyhow!("No point field [{}]", name))
+    }
+
+    /// Values of the named field, whose datatype must be the one of `T`.
+    pub fn field_iter<T: PointField>(&self, name: &str) -> Result<FieldIter<'_, T>> {
+        let field = self.find_field(name)?;
+        if field.datatype != T::DATATYPE as i32 {
+            bail!(
+                "Point field [{}] is not {}",
+                name,
+                T::DATATYPE.as_str_name()
+            );
+        }
+        Ok(FieldIter::new(self, field))
+    }
+
+    /// Positions of the points, from the FLOAT32 `x`, `y` and `z` fields.
+    pub fn xyz(&self) -> Result<impl Iterator<Item = [f32; 3]> + '_> {
+        let x = self.field_iter::<f32>("x")?;
+        let y = self.field_iter::<f32>("y")?;
+        let z = self.field_iter::<f32>("z")?;
+        Ok(x.zip(y).zip(z).map(|((x, y), z)| [x, y, z]))
+    }
+
+    /// Colors of the points, from the `rgb` field packed in a FLOAT32 or UINT32.
+    pub fn rgb(&self) -> Result<impl Iterator<Item = [u8; 3]> + '_> {
+        let field = self.find_field("rgb")?;
+        if field.datatype != DataType::Float32 as i32 && field.datatype != DataType::Uint32 as i32 {
+            bail!("Point field [rgb] is not FLOAT32 or UINT32");
+        }
+        Ok(FieldIter::<u32>::new(self, field).map(|bits| unpack_rgb(f32::from_bits(bits))))
+    }
+}
+
+/// Packs points into a single row `PointCloudPacked`.
+///
+/// ```
+/// use rgz_msgs::point_cloud_packed::field::DataType;
+/// use rgz_msgs::view::PointCloudBuilder;
+///
+/// let mut builder = PointCloudBuilder::xyz().field("intensity", DataType::Float32);
+/// builder.push(&[1.0, 2.0, 3.0, 0.5]).unwrap();
+/// let cloud = builder.build();
+/// assert_eq!(cloud.xyz().unwrap().next(), Some([1.0, 2.0, 3.0]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PointCloudBuilder {
+    fields: Vec<Field>,
+    point_step: u32,
+    width: u32,
+    data: Vec<u8>,
+}
+
+impl PointCloudBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The FLOAT32 `x`, `y` and `z` fields.
+    pub fn xyz() -> Self {
+        Self::new()
+            .field("x", DataType::Float32)
+            .field("y", DataType::Float32)
+            .field("z", DataType::Float32)
+    }
+
+    /// Add a field after the previous ones.
+    pub fn field(mut self, name: &str, datatype: DataType) -> Self {
+        self.fields.push(Field {
+            name: name.to_string(),
+            offset: self.point_step,
+            datatype: datatype as i32,
+            count: 1,
+        });
+        self.point_step += datatype.size();
+        self
+    }
+
+    /// Append a point, with a value per field in the order of the fields.
+    ///
+    /// The values are cast to the field datatypes, use `pack_rgb()` for colors.
+    pub fn push(&mut self, values: &[f64]) -> Result<()> {
+        if values.len() != self.fields.len() {
+            bail!(
+                "Got {} values for {} point fields",
+                values.len(),
+                self.fields.len()
+            );
+        }
+        let start = self.data.len();
+        self.data.resize(start + self.point_step as usize, 0);
+        for (field, &value) in self.fields.iter().zip(values) {
+            let out = &mut self.data[start + field.offset as usize..];
+            match DataType::try_from(field.datatype).unwrap() {
+                DataType::Int8 => (value as i8).write(out, false),
+                DataType::Uint8 => (value as u8).write(out, false),
+                DataType::Int16 => (value as i16).write(out, false),
+                DataType::Uint16 => (value as u16).write(out, false),
+                DataType::Int32 => (value as i32).write(out, false),
+                DataType::Uint32 => (value as u32).write(out, false),
+                DataType::Float32 => (value as f32).write(out, false),
+                DataType::Float64 => value.write(out, false),
+            }
+        }
+        self.width += 1;
+        Ok(())
+    }
+
+    pub fn build(self) -> PointCloudPacked {
+        PointCloudPacked {
+            field: self.fields,
+            height: 1,
+            width: self.width,
+            is_bigendian: false,
+            point_step: self.point_step,
+            row_step: self.width * self.point_step,
+            data: self.data,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_cloud() {
+        let mut builder = PointCloudBuilder::xyz()
+            .field("rgb", DataType::Float32)
+            .field("ring", DataType::Uint16);
+        builder
+            .push(&[1.0, 2.0, 3.0, pack_rgb([255, 128, 0]) as f64, 7.0])
+            .unwrap();
+        builder
+            .push(&[-1.0, 0.5, 0.0, pack_rgb([0, 0, 1]) as f64, 8.0])
+            .unwrap();
+        assert!(builder.push(&[0.0]).is_err());
+        let cloud = builder.build();
+        assert_eq!(cloud.validate(), Ok(()));
+        assert_eq!((cloud.width, cloud.point_step), (2, 18));
+
+        assert_eq!(
+            cloud.xyz().unwrap().collect::<Vec<_>>(),
+            vec![[1.0, 2.0, 3.0], [-1.0, 0.5, 0.0]]
+        );
+        assert_eq!(
+            cloud.rgb().unwrap().collect::<Vec<_>>(),
+            vec![[255, 128, 0], [0, 0, 1]]
+        );
+        let ring = cloud.field_iter::<u16>("ring").unwrap();
+        assert_eq!(ring.len(), 2);
+        assert_eq!(ring.collect::<Vec<_>>(), vec![7, 8]);
+        assert!(cloud.field_iter::<f32>("ring").is_err());
+        assert!(cloud.field_iter::<f32>("intensity").is_err());
+    }
+
+    #[test]
+    fn test_padded_big_endian() {
+        // Two rows of one point, padded to 8 bytes.
+        let mut data = vec![0; 16];
+        data[2..6].copy_from_slice(&1.5f32.to_be_bytes());
+        data[10..14].copy_from_slice(&2.5f32.to_be_bytes());
+        let cloud = PointCloudPacked {
+            field: vec![Field {
+                name: "intensity".to_string(),
+                offset: 2,
+                datatype: DataType::Float32 as i32,
+                count: 1,
+            }],
+            height: 2,
+            width: 1,
+            is_bigendian: true,
+            point_step: 6,
+            row_step: 8,
+            data,
+            ..Default::default()
+        };
+        let intensity = cloud.field_iter::<f32>("intensity").unwrap();
+        assert_eq!(intensity.collect::<Vec<_>>(), vec![1.5, 2.5]);
+    }
+}
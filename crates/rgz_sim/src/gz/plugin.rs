@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy::utils::HashMap;
 use rgz_msgs as msgs;
+use rgz_msgs::Validate;
 
 use crate::AppArgs;
 use crate::gz::api::{
@@ -169,6 +170,10 @@ fn update_pose(mut query: Query<(&mut Transform, &GzId)>, mut events: EventReade
     for event in events.read() {
         for (mut transform, gz_id) in &mut query {
             if let Some(pose) = event.pose_map.get(&gz_id.id) {
+                if let Err(err) = pose.validate() {
+                    warn!("invalid pose of entity {}: {}", gz_id.id, err);
+                    continue;
+                }
                 transform.translation = utils::vec3(pose.position.as_ref().unwrap());
                 transform.rotation = utils::quat(pose.orientation.as_ref().unwrap());
             }
@@ -44,8 +44,7 @@ pub(crate) fn geometry(geometry: Option<&msgs::Geometry>) -> Option<Mesh> {
             }
         }
         msgs::geometry::Type::Plane => {
-            if let Some(plane_geom) = geometry.plane.as_ref() {
-                let size = plane_geom.size.as_ref().unwrap();
+            if let Some(size) = geometry.plane.as_ref().and_then(|p| p.size.as_ref()) {
                 let s = size.x.max(size.y);
                 return Some(Mesh::from(shape::Plane::from_size(s as f32)));
             }
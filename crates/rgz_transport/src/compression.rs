@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use anyhow::{bail, Result};
+
+/// First byte of a compressed payload. A protobuf message never starts with it,
+/// field 0 is invalid, so uncompressed payloads are sent as gz-transport does.
+const MARKER: u8 = 0;
+/// Codec byte following the marker.
+const LZ4_ID: u8 = 1;
+
+/// Refuse to decompress payloads announcing a bigger size.
+const MAX_DECOMPRESSED_SIZE: usize = 1 << 30;
+/// A byte of an LZ4 block produces at most 255 bytes, with the length bytes
+/// of a long match.
+const MAX_RATIO: usize = 255;
+
+/// Compression of the messages of a topic.
+///
+/// Compressed payloads start with the marker and codec bytes, so subscribers
+/// decompress the messages transparently. gz-transport peers only understand
+/// `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    #[default]
+    None,
+    /// LZ4 block format, prefixed by the decompressed size (u32 LE).
+    Lz4,
+}
+
+impl Compression {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Compression::None => "none",
+            Compression::Lz4 => "lz4",
+        }
+    }
+
+    pub(crate) fn compress(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Compression::None => data.to_vec(),
+            Compression::Lz4 => {
+                let mut payload = vec![MARKER, LZ4_ID];
+                payload.extend(lz4_flex::compress_prepend_size(data));
+                payload
+            }
+        }
+    }
+
+    /// Decompress a payload with the codec written in front of it.
+    pub(crate) fn decompress(payload: Vec<u8>) -> Result<Vec<u8>> {
+        if payload.first() != Some(&MARKER) {
+            return Ok(payload);
+        }
+        match payload.get(1) {
+            Some(&LZ4_ID) => lz4_decompress(&payload[2..]),
+            Some(id) => bail!("Unsupported compression [{}]", id),
+            None => bail!("Compressed payload without codec"),
+        }
+    }
+}
+
+type Topics = Mutex<HashMap<String, (Compression, Weak<CompressionRef>)>>;
+
+/// Codec of the topics advertised in this process.
+///
+/// The messages of a topic are sent once to the remote subscribers, so every
+/// publisher of the process must use the same codec. A topic is registered
+/// while one of its publishers holds the `CompressionRef`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TopicCompressions {
+    topics: Arc<Topics>,
+}
+
+impl TopicCompressions {
+    pub(crate) fn register(
+        &self,
+        topic: &str,
+        compression: Compression,
+    ) -> Result<Arc<CompressionRef>> {
+        let mut topics = self.topics.lock().unwrap();
+        let registered = topics.get(topic).and_then(|(registered, compression_ref)| {
+            Some((*registered, compression_ref.upgrade()?))
+        });
+        if let Some((registered, compression_ref)) = registered {
+            // Dropping the last reference locks the topics.
+            drop(topics);
+            if registered != compression {
+                bail!(
+                    "[{}] is already advertised with the [{}] compression",
+                    topic,
+                    registered.name()
+                );
+            }
+            return Ok(compression_ref);
+        }
+        let compression_ref = Arc::new(CompressionRef {
+            topic: topic.to_string(),
+            topics: Arc::downgrade(&self.topics),
+        });
+        topics.insert(topic.to_string(), (compression, Arc::downgrade(&compression_ref)));
+        Ok(compression_ref)
+    }
+
+    pub(crate) fn get(&self, topic: &str) -> Compression {
+        let topics = self.topics.lock().unwrap();
+        topics.get(topic).map(|(compression, _)| *compression).unwrap_or_default()
+    }
+}
+
+/// Keeps the codec of a topic registered, the publishers of the topic share it.
+#[derive(Debug)]
+pub(crate) struct CompressionRef {
+    topic: String,
+    topics: Weak<Topics>,
+}
+
+impl Drop for CompressionRef {
+    fn drop(&mut self) {
+        let Some(topics) = self.topics.upgrade() else {
+            return;
+        };
+        let mut topics = topics.lock().unwrap();
+        // Another publisher may have registered the topic again meanwhile.
+        let unused = topics
+            .get(&self.topic)
+            .is_some_and(|(_, compression_ref)| compression_ref.strong_count() == 0);
+        if unused {
+            topics.remove(&self.topic);
+        }
+    }
+}
+
+fn lz4_decompress(input: &[u8]) -> Result<Vec<u8>> {
+    let (size, block) = match lz4_flex::block::uncompressed_size(input) {
+        Ok(result) => result,
+        Err(err) => bail!("Invalid LZ4 block: {}", err),
+    };
+    // The announced size comes from the peer, don't allocate more than the
+    // block can produce.
+    let max_size = (block.len() * MAX_RATIO).min(MAX_DECOMPRESSED_SIZE);
+    if size > max_size {
+        bail!("LZ4 block too large [{}]", size);
+    }
+    let out = match lz4_flex::block::decompress(block, size) {
+        Ok(out) => out,
+        Err(err) => bail!("Invalid LZ4 block: {}", err),
+    };
+    if out.len() != size {
+        bail!("LZ4 block does not match its announced size");
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lz4() {
+        // Hand made block: "abc", match (offset 3, len 9), "xyzab".
+        let block = [
+            17, 0, 0, 0, 0x35, b'a', b'b', b'c', 3, 0, 0x50, b'x', b'y', b'z', b'a', b'b',
+        ];
+        assert_eq!(lz4_decompress(&block).unwrap(), b"abcabcabcabcxyzab");
+
+        let mut rng = 0x1234_5678u32;
+        let noise: Vec<u8> = (0..3000)
+            .map(|_| {
+                rng ^= rng << 13;
+                rng ^= rng >> 17;
+                rng ^= rng << 5;
+                rng as u8
+            })
+            .collect();
+        let grid: Vec<u8> = (0..100_000).map(|i| if i % 1000 < 900 { 0 } else { 100 }).collect();
+        for data in [vec![], b"short".to_vec(), noise, grid.clone()] {
+            let compressed = Compression::Lz4.compress(&data);
+            assert_eq!(Compression::decompress(compressed).unwrap(), data);
+        }
+        assert!(Compression::Lz4.compress(&grid).len() < grid.len() / 50);
+
+        assert!(lz4_decompress(&block[..12]).is_err());
+        assert!(lz4_decompress(&[17, 0, 0, 0, 0x35, b'a', b'b', b'c', 9, 0, 0]).is_err());
+        // The size is bounded by the length of the block.
+        assert!(lz4_decompress(&[0xFF, 0xFF, 0xFF, 0x3F, 0x00]).is_err());
+        let zeros = vec![0; 1_000_000];
+        let compressed = Compression::Lz4.compress(&zeros);
+        assert_eq!(Compression::decompress(compressed).unwrap(), zeros);
+    }
+
+    #[test]
+    fn test_payload() {
+        // Uncompressed payloads are left as is, protobuf messages never start
+        // with the marker.
+        let data = vec![10, 5, b'h', b'e', b'l', b'l', b'o'];
+        assert_eq!(Compression::None.compress(&data), data);
+        assert_eq!(Compression::decompress(data.clone()).unwrap(), data);
+        assert!(Compression::decompress(vec![]).unwrap().is_empty());
+
+        let payload = Compression::Lz4.compress(&data);
+        assert_eq!(payload[..2], [MARKER, LZ4_ID]);
+        assert_eq!(Compression::decompress(payload).unwrap(), data);
+        assert!(Compression::decompress(vec![MARKER]).is_err());
+        assert!(Compression::decompress(vec![MARKER, 7, 0]).is_err());
+    }
+
+    #[test]
+    fn test_topic_compressions() {
+        let compressions = TopicCompressions::default();
+        let lz4 = compressions.register("/foo", Compression::Lz4).unwrap();
+        let lz4_clone = compressions.register("/foo", Compression::Lz4).unwrap();
+        assert!(compressions.register("/foo", Compression::None).is_err());
+        assert_eq!(compressions.get("/foo"), Compression::Lz4);
+        assert_eq!(compressions.get("/bar"), Compression::None);
+
+        drop(lz4);
+        assert_eq!(compressions.get("/foo"), Compression::Lz4);
+        drop(lz4_clone);
+        assert!(compressions.topics.lock().unwrap().is_empty());
+        let _none = compressions.register("/foo", Compression::None).unwrap();
+        assert_eq!(compressions.get("/foo"), Compression::None);
+    }
+}
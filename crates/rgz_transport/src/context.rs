@@ -0,0 +1,237 @@
+use std::env;
+
+use anyhow::Result;
+use tracing::warn;
+
+use crate::discovery::DiscoveryBackendType;
+use crate::node::{
+    NodeShared, ServiceSelection, DEFAULT_DISCOVERY_IP, DEFAULT_MSG_DISC_PORT,
+    DEFAULT_SRV_DISC_PORT,
+};
+use crate::security::{CurveKeys, PlainCredentials, Security};
+use crate::utils::env as env_utils;
+
+/// Process wide transport settings.
+///
+/// `TransportContext::new()` reads the usual `GZ_*` environment variables.
+/// Pass the context to `init()` before creating the first `Node` to override them.
+#[derive(Debug, Clone)]
+pub struct TransportContext {
+    domain: String,
+    discovery_ip: String,
+    msg_disc_port: u16,
+    srv_disc_port: u16,
+    verbose: bool,
+    discovery_backend: DiscoveryBackendType,
+    plain_credentials: Option<PlainCredentials>,
+    curve_keys: Option<CurveKeys>,
+    ipc: bool,
+    service_selection: ServiceSelection,
+    coalesce_publish: bool,
+}
+
+impl TransportContext {
+    pub fn new() -> Self {
+        let mut verbose = false;
+        let mut discovery_ip = DEFAULT_DISCOVERY_IP.to_string();
+        // If GZ_VERBOSE=1 enable the verbose mode.
+        if let Ok(gz_verbose) = env::var("GZ_VERBOSE") {
+            if !gz_verbose.is_empty() {
+                verbose = gz_verbose == "1";
+            }
+        }
+        // Set the multicast IP used for discovery.
+        if let Ok(ip) = env::var("GZ_DISCOVERY_MULTICAST_IP") {
+            if !ip.is_empty() {
+                discovery_ip = ip;
+            }
+        }
+        // Set the port used for msg discovery.
+        let msg_disc_port =
+            env_utils::non_negative_env_var("GZ_DISCOVERY_MSG_PORT", DEFAULT_MSG_DISC_PORT);
+        // Set the port used for srv discovery.
+        let srv_disc_port =
+            env_utils::non_negative_env_var("GZ_DISCOVERY_SRV_PORT", DEFAULT_SRV_DISC_PORT);
+
+        let mut context = TransportContext {
+            domain: "".to_string(),
+            discovery_ip,
+            msg_disc_port,
+            srv_disc_port,
+            verbose,
+            discovery_backend: DiscoveryBackendType::default(),
+            plain_credentials: PlainCredentials::from_env(),
+            curve_keys: None,
+            ipc: false,
+            service_selection: ServiceSelection::default(),
+            coalesce_publish: false,
+        };
+        context.check_ports();
+        context
+    }
+
+    // Sanity check: the discovery ports should be unique.
+    fn check_ports(&mut self) {
+        if self.msg_disc_port == self.srv_disc_port {
+            if self.msg_disc_port < 65535 {
+                self.srv_disc_port += 1;
+            } else {
+                self.srv_disc_port -= 1;
+            }
+            eprintln!(
+                "Your discovery ports are the same [{}]. \
+                Using [{}] for messages and [{}] for services",
+                self.msg_disc_port, self.msg_disc_port, self.srv_disc_port
+            );
+        }
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+    /// Configure the transport of a domain instead of the default one.
+    ///
+    /// Each domain has its own sockets and discovery, and the other domains of
+    /// the process see it as another process. Nodes choose theirs with
+    /// `NodeOptions::set_domain()`, e.g. to connect nodes of one process
+    /// through the `InMemory` discovery backend.
+    pub fn set_domain(&mut self, domain: &str) {
+        self.domain = domain.to_string();
+    }
+    pub fn discovery_ip(&self) -> &str {
+        &self.discovery_ip
+    }
+    pub fn set_discovery_ip(&mut self, ip: &str) {
+        self.discovery_ip = ip.to_string();
+    }
+    pub fn msg_disc_port(&self) -> u16 {
+        self.msg_disc_port
+    }
+    pub fn set_msg_disc_port(&mut self, port: u16) {
+        self.msg_disc_port = port;
+        self.check_ports();
+    }
+    pub fn srv_disc_port(&self) -> u16 {
+        self.srv_disc_port
+    }
+    pub fn set_srv_disc_port(&mut self, port: u16) {
+        self.srv_disc_port = port;
+        self.check_ports();
+    }
+    pub fn verbose(&self) -> bool {
+        self.verbose
+    }
+    pub fn set_verbose(&mut self, verbose: bool) {
+        self.verbose = verbose;
+    }
+    pub fn discovery_backend(&self) -> &DiscoveryBackendType {
+        &self.discovery_backend
+    }
+    pub fn set_discovery_backend(&mut self, backend: DiscoveryBackendType) {
+        self.discovery_backend = backend;
+    }
+    pub fn plain_credentials(&self) -> Option<&PlainCredentials> {
+        self.plain_credentials.as_ref()
+    }
+    /// Require a user name and password on the publisher/subscriber connections.
+    pub fn set_plain_credentials(&mut self, credentials: Option<PlainCredentials>) {
+        self.plain_credentials = credentials;
+    }
+    pub fn curve_keys(&self) -> Option<&CurveKeys> {
+        self.curve_keys.as_ref()
+    }
+    /// Encrypt the publisher/subscriber connections. It replaces PLAIN if both are set.
+    pub fn set_curve_keys(&mut self, keys: Option<CurveKeys>) {
+        self.curve_keys = keys;
+    }
+    pub fn ipc(&self) -> bool {
+        self.ipc
+    }
+    /// Exchange messages through unix domain sockets with the processes on this host.
+    ///
+    /// Both the publisher and the subscriber processes must enable it. Remote peers
+    /// keep using TCP.
+    pub fn set_ipc(&mut self, ipc: bool) {
+        self.ipc = ipc;
+    }
+    pub fn service_selection(&self) -> ServiceSelection {
+        self.service_selection
+    }
+    /// Choose how requests pick among the processes providing a service.
+    pub fn set_service_selection(&mut self, selection: ServiceSelection) {
+        self.service_selection = selection;
+    }
+    pub fn coalesce_publish(&self) -> bool {
+        self.coalesce_publish
+    }
+    /// Handle the publications queued at once in a single batch.
+    ///
+    /// It reduces the per message overhead of high rate publishers.
+    pub fn set_coalesce_publish(&mut self, coalesce_publish: bool) {
+        self.coalesce_publish = coalesce_publish;
+    }
+    pub(crate) fn security(&self) -> Security {
+        Security {
+            plain: self.plain_credentials.clone(),
+            curve: self.curve_keys.clone(),
+        }
+    }
+}
+
+impl Default for TransportContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Initialize the transport of the context's domain.
+///
+/// It fails if a `Node` has already been created in this domain.
+pub fn init(context: TransportContext) -> Result<()> {
+    NodeShared::init(context)
+}
+
+/// Shut down the transports of every domain of this process.
+///
+/// All the topics and services are unadvertised, a BYE message is sent to the
+/// peers and the sockets are closed. Existing nodes stop working; nodes created
+/// afterwards start a new transport.
+///
+/// Await it before the runtime goes away, e.g. at the end of `main`: the
+/// transport runs on the runtime's tasks, which are not polled anymore once
+/// it is dropped.
+pub async fn shutdown() -> Result<()> {
+    NodeShared::shutdown().await
+}
+
+/// Reminds to shut down the transport before leaving a scope.
+///
+/// Drops can't wait for the transport's tasks, so `close()` must be awaited to
+/// send the BYE messages. Dropping the guard without it only logs a warning.
+#[derive(Debug, Default)]
+#[must_use = "await `close()` to shut down the transport"]
+pub struct ShutdownGuard {
+    closed: bool,
+}
+
+impl ShutdownGuard {
+    pub fn new() -> Self {
+        ShutdownGuard { closed: false }
+    }
+    /// Shut down the transports, see `shutdown()`.
+    pub async fn close(mut self) -> Result<()> {
+        self.closed = true;
+        shutdown().await
+    }
+}
+
+impl Drop for ShutdownGuard {
+    fn drop(&mut self) {
+        if !self.closed {
+            warn!(
+                "ShutdownGuard dropped without close(), \
+                the peers only forget this process after the silence timeout"
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::Mutex;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::discovery::backend::DiscoveryBackend;
+
+type MemoryBus = HashMap<u16, Vec<UnboundedSender<Vec<u8>>>>;
+
+// All the in-memory backends of this process, grouped by discovery port.
+static MEMORY_BUS: Lazy<Mutex<MemoryBus>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub(crate) struct InMemoryBackend {
+    port: u16,
+    receiver: tokio::sync::Mutex<UnboundedReceiver<Vec<u8>>>,
+}
+
+impl InMemoryBackend {
+    pub(crate) fn new(port: u16) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        MEMORY_BUS
+            .lock()
+            .unwrap()
+            .entry(port)
+            .or_default()
+            .push(sender);
+        InMemoryBackend {
+            port,
+            receiver: tokio::sync::Mutex::new(receiver),
+        }
+    }
+}
+
+#[async_trait]
+impl DiscoveryBackend for InMemoryBackend {
+    async fn send_multicast(&self, buf: &[u8]) -> io::Result<usize> {
+        let mut bus = MEMORY_BUS.lock().unwrap();
+        if let Some(senders) = bus.get_mut(&self.port) {
+            // Drop the backends that have gone away.
+            senders.retain(|sender| sender.send(buf.to_vec()).is_ok());
+        }
+        Ok(buf.len())
+    }
+
+    async fn send_to(&self, buf: &[u8], _addr: SocketAddrV4) -> io::Result<usize> {
+        // There are no relays inside a process.
+        Ok(buf.len())
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        let mut receiver = self.receiver.lock().await;
+        match receiver.recv().await {
+            Some(data) => {
+                let len = data.len().min(buf.len());
+                buf[..len].copy_from_slice(&data[..len]);
+                let addr = SocketAddrV4::new(Ipv4Addr::LOCALHOST, self.port);
+                Ok((len, addr.into()))
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "The in-memory discovery bus is closed",
+            )),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+mod memory;
+mod multicast;
+mod static_peers;
+
+pub(crate) use memory::InMemoryBackend;
+pub(crate) use multicast::MulticastBackend;
+pub(crate) use static_peers::StaticBackend;
+
+use async_trait::async_trait;
+use std::io;
+use std::net::{SocketAddr, SocketAddrV4};
+use std::path::PathBuf;
+
+/// The mechanism used to exchange discovery messages with other processes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum DiscoveryBackendType {
+    /// UDP multicast, compatible with gz-transport (default).
+    #[default]
+    Multicast,
+    /// UDP unicast to a fixed list of peers read from a file. Each line of the
+    /// file contains an IPv4 address, optionally followed by `:port`. The
+    /// discovery port is used when no port is given. Empty lines and lines
+    /// starting with `#` are ignored.
+    Static(PathBuf),
+    /// Discovery between the nodes of this process only, without sockets.
+    InMemory,
+}
+
+/// Sends and receives raw discovery datagrams.
+#[async_trait]
+pub(crate) trait DiscoveryBackend: Send + Sync {
+    /// Send a datagram to every peer of the discovery group.
+    async fn send_multicast(&self, buf: &[u8]) -> io::Result<usize>;
+
+    /// Send a datagram to a single peer.
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> io::Result<usize>;
+
+    /// Receive a datagram and the address of its sender.
+    async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)>;
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tracing::{debug, error};
+
+use crate::discovery::backend::DiscoveryBackend;
+use crate::discovery::TIMEOUT;
+
+pub(crate) struct MulticastBackend {
+    socket: UdpSocket,
+    multicast_addr: SocketAddrV4,
+}
+
+impl MulticastBackend {
+    /// Join the multicast group on every host interface. `host_addr` falls
+    /// back to 127.0.0.1 if it is not a valid local interface.
+    pub(crate) fn new(
+        multicast_addr: SocketAddrV4,
+        host_interfaces: &[Ipv4Addr],
+        host_addr: &mut Ipv4Addr,
+    ) -> Result<Self> {
+        use socket2::{Domain, Protocol, Socket, Type};
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+
+        socket.set_read_timeout(Some(Duration::from_millis(TIMEOUT as u64)))?;
+        socket.set_multicast_loop_v4(true)?;
+        socket.set_reuse_address(true)?;
+        socket.set_reuse_port(true)?;
+        socket.set_nonblocking(true)?;
+
+        for net_iface in host_interfaces.iter() {
+            if let Err(_err) = socket.join_multicast_v4(multicast_addr.ip(), net_iface) {
+                if net_iface == host_addr {
+                    let addr = "127.0.0.1:0".parse::<SocketAddrV4>().unwrap();
+                    socket.join_multicast_v4(multicast_addr.ip(), addr.ip())?;
+                    error!(
+                        "Did you set the environment variable GZ_IP with a correct IP address? "
+                    );
+                    error!("  [{}] seems an invalid local IP address.", net_iface);
+                    error!("  Using 127.0.0.1 as hostname.");
+                    *host_addr = *addr.ip();
+                } else {
+                    error!(
+                        "Failed to join multicast group [{}] on interface [{}].",
+                        multicast_addr.ip(),
+                        net_iface
+                    );
+                }
+            } else {
+                debug!(
+                    "Joining multicast group [{}] on interface [{}].",
+                    multicast_addr.ip(),
+                    net_iface
+                );
+            }
+        }
+
+        let address = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), multicast_addr.port());
+        socket.bind(&address.into())?;
+
+        let socket = UdpSocket::from_std(socket.into())?;
+        Ok(MulticastBackend {
+            socket,
+            multicast_addr,
+        })
+    }
+}
+
+#[async_trait]
+impl DiscoveryBackend for MulticastBackend {
+    async fn send_multicast(&self, buf: &[u8]) -> io::Result<usize> {
+        // TODO: Support multiple Sockets.
+        self.socket.send_to(buf, self.multicast_addr).await
+    }
+
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> io::Result<usize> {
+        self.socket.send_to(buf, addr).await
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.socket.recv_from(buf).await
+    }
+}
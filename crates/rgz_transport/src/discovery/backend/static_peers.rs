@@ -0,0 +1,95 @@
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use std::fs;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::path::Path;
+use tokio::net::UdpSocket;
+use tracing::debug;
+
+use crate::discovery::backend::DiscoveryBackend;
+
+pub(crate) struct StaticBackend {
+    socket: UdpSocket,
+    peers: Vec<SocketAddrV4>,
+}
+
+impl StaticBackend {
+    /// Bind the discovery port and read the list of peers from `path`.
+    pub(crate) fn new(path: &Path, port: u16) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the peer file [{}]", path.display()))?;
+        let peers = parse_peers(&contents, port)?;
+
+        use socket2::{Domain, Protocol, Socket, Type};
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+        socket.set_reuse_address(true)?;
+        socket.set_nonblocking(true)?;
+        let address = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port);
+        socket.bind(&address.into())?;
+
+        let socket = UdpSocket::from_std(socket.into())?;
+        Ok(StaticBackend { socket, peers })
+    }
+}
+
+fn parse_peers(contents: &str, port: u16) -> Result<Vec<SocketAddrV4>> {
+    let mut peers = vec![];
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Ok(addr) = line.parse::<SocketAddrV4>() {
+            peers.push(addr);
+        } else if let Ok(ip) = line.parse::<Ipv4Addr>() {
+            peers.push(SocketAddrV4::new(ip, port));
+        } else {
+            bail!("Invalid peer address [{}]", line);
+        }
+    }
+    Ok(peers)
+}
+
+#[async_trait]
+impl DiscoveryBackend for StaticBackend {
+    async fn send_multicast(&self, buf: &[u8]) -> io::Result<usize> {
+        // Emulate the multicast group by sending to every peer.
+        let mut result = Ok(buf.len());
+        for peer in &self.peers {
+            if let Err(err) = self.socket.send_to(buf, peer).await {
+                debug!("Failed to send a discovery message to [{}]: {}", peer, err);
+                result = Err(err);
+            }
+        }
+        result
+    }
+
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> io::Result<usize> {
+        self.socket.send_to(buf, addr).await
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.socket.recv_from(buf).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_peers() {
+        let contents = "# robots\n10.0.0.1\n\n10.0.0.2:11400\n";
+        let peers = parse_peers(contents, 10317).unwrap();
+        assert_eq!(
+            peers,
+            vec![
+                "10.0.0.1:10317".parse::<SocketAddrV4>().unwrap(),
+                "10.0.0.2:11400".parse::<SocketAddrV4>().unwrap(),
+            ]
+        );
+
+        assert!(parse_peers("robot1", 10317).is_err());
+    }
+}
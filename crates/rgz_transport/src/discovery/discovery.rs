@@ -1,18 +1,22 @@
 use anyhow::{bail, Result};
+use rgz_msgs as msgs;
 use std::collections::{HashMap, HashSet};
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::sync::{Arc, Mutex};
 use std::{env, io};
-use tokio::net::UdpSocket;
 use tokio::select;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::task::JoinHandle;
 use tokio::time::{self, Duration, Instant};
 use tracing::{debug, error, info, trace, warn};
 
+use crate::discovery::backend::{
+    DiscoveryBackend, DiscoveryBackendType, InMemoryBackend, MulticastBackend, StaticBackend,
+};
 use crate::discovery::store::DiscoveryStore;
 use crate::discovery::{
     discovery_msg_decode, discovery_msg_encode, version, DEF_ACTIVITY_INTERVAL,
-    DEF_HEARTBEAT_INTERVAL, DEF_SILENCE_INTERVAL, MAX_RCV_STR, TIMEOUT,
+    DEF_HEARTBEAT_INTERVAL, DEF_SILENCE_INTERVAL, IPC_HEADER_KEY, MAX_RCV_STR,
 };
 use crate::discovery::{
     DiscoveryDiscContents, DiscoveryFlags, DiscoveryMsg, DiscoveryPublisher, DiscoveryScope,
@@ -40,8 +44,9 @@ pub(crate) struct Discovery {
     // Discovery wire protocol version.
     version: u32,
 
-    // UDP socket used for sending/receiving discovery messages.
-    socket: Option<Arc<UdpSocket>>,
+    // Mechanism used for sending/receiving discovery messages.
+    backend_type: DiscoveryBackendType,
+    backend: Option<Arc<dyn DiscoveryBackend>>,
 
     // Process UUID.
     p_uuid: String,
@@ -81,7 +86,11 @@ pub(crate) struct Discovery {
     // Print discovery information to stdout.
     verbose: bool,
 
+    // IPC endpoint announced along with our publishers.
+    ipc_address: Option<String>,
+
     msg_sender: Option<UnboundedSender<SendMsg>>,
+    handle: Option<JoinHandle<()>>,
 }
 
 impl Discovery {
@@ -113,7 +122,8 @@ impl Discovery {
 
         Discovery {
             version: version(),
-            socket: None,
+            backend_type: DiscoveryBackendType::default(),
+            backend: None,
             p_uuid,
             host_addr,
             host_interfaces,
@@ -128,10 +138,22 @@ impl Discovery {
             registration_cb: Arc::new(Mutex::new(None)),
             unregistration_cb: Arc::new(Mutex::new(None)),
             verbose,
+            ipc_address: None,
             msg_sender: None,
+            handle: None,
         }
     }
 
+    /// Select the discovery backend. It must be called before `start()`.
+    pub(crate) fn set_backend_type(&mut self, backend_type: DiscoveryBackendType) {
+        self.backend_type = backend_type;
+    }
+
+    /// Announce an IPC endpoint in our ADVERTISE messages. It must be called before `start()`.
+    pub(crate) fn set_ipc_address(&mut self, address: Option<String>) {
+        self.ipc_address = address;
+    }
+
     pub(crate) fn set_connection_cb<F>(&mut self, callback: F)
     where
         F: Fn(DiscoveryPublisher) + Send + Sync + 'static,
@@ -284,6 +306,13 @@ impl Discovery {
         Ok(())
     }
 
+    // Store a subscriber of this process. Remote subscribers are learned from
+    // SUBSCRIBE and NEW_CONNECTION messages.
+    pub fn register_subscriber(&self, subscriber: DiscoveryPublisher) -> Result<()> {
+        let mut store = self.discovery_store.lock().unwrap();
+        store.add_subscriber(subscriber)
+    }
+
     // Get all the publishers' information known for a given topic.
     pub fn publishers(&self, topic: &str) -> Option<Vec<DiscoveryPublisher>> {
         let store = self.discovery_store.lock().unwrap();
@@ -315,6 +344,36 @@ impl Discovery {
         &self.host_addr
     }
 
+    pub fn host_interfaces(&self) -> &[Ipv4Addr] {
+        &self.host_interfaces
+    }
+
+    // Get the IPC endpoint announced by a remote process.
+    pub(crate) fn ipc_address(&self, p_uuid: &str) -> Option<String> {
+        let store = self.discovery_store.lock().unwrap();
+        store.ipc_address(p_uuid).map(|s| s.to_string())
+    }
+
+    // Unadvertise all the topics advertised by this process.
+    pub(crate) fn unadvertise_all(&self) -> Result<()> {
+        let publishers: Vec<(String, String)> = {
+            let store = self.discovery_store.lock().unwrap();
+            store
+                .publishers_by_process(&self.p_uuid)
+                .into_iter()
+                .map(|p| (p.topic.clone(), p.node_uuid.clone()))
+                .collect()
+        };
+        for (topic, n_uuid) in publishers {
+            self.unadvertise(&topic, &n_uuid)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn discovery_store(&self) -> Arc<Mutex<DiscoveryStore>> {
+        self.discovery_store.clone()
+    }
+
     pub fn print_current_state(&self) {
         {
             let store = self.discovery_store.lock().unwrap();
@@ -329,13 +388,14 @@ impl Discovery {
         let mut heartbeat_interval = time::interval(Duration::from_millis(self.heartbeat_interval));
         let mut activity_interval = time::interval(Duration::from_millis(self.activity_interval));
 
-        self.register_host_interfaces()
-            .expect("Failed to register host interfaces.");
-        let socket = self.socket.clone().unwrap();
-        let inner = DiscoveryInner::new(
+        let backend = self
+            .create_backend()
+            .expect("Failed to create the discovery backend.");
+        self.backend = Some(backend.clone());
+        let mut inner = DiscoveryInner::new(
             self.version,
             self.p_uuid.clone(),
-            socket.clone(),
+            backend.clone(),
             self.host_interfaces.clone(),
             self.multicast_addr.clone(),
             self.relay_addrs.clone(),
@@ -347,8 +407,9 @@ impl Discovery {
             self.unregistration_cb.clone(),
             self.verbose,
         );
+        inner.ipc_address = self.ipc_address.clone();
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(async move {
             let mut rcv_str = vec![0u8; MAX_RCV_STR];
             loop {
                 select! {
@@ -366,7 +427,7 @@ impl Discovery {
                         }
                     }
 
-                    Ok((len, addr)) = socket.recv_from(&mut rcv_str) => {
+                    Ok((len, addr)) = backend.recv_from(&mut rcv_str) => {
                         if let Err(err) = inner.recv_messages(rcv_str.clone(), len, addr).await {
                             error!("Failed to receive discovery message: {}", err);
                         }
@@ -387,68 +448,52 @@ impl Discovery {
             }
             debug!("Discovery thread finished. [{}]", inner.p_uuid);
         });
+        self.handle = Some(handle);
     }
-    fn register_host_interfaces(&mut self) -> Result<()> {
-        use socket2::{Domain, Protocol, Socket, Type};
-        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
-
-        socket.set_read_timeout(Some(Duration::from_millis(TIMEOUT as u64)))?;
-        socket.set_multicast_loop_v4(true)?;
-        socket.set_reuse_address(true)?;
-        socket.set_reuse_port(true)?;
-        socket.set_nonblocking(true)?;
-
-        for net_iface in self.host_interfaces.iter() {
-            if let Err(_err) = socket.join_multicast_v4(self.multicast_addr.ip(), net_iface) {
-                if net_iface == &self.host_addr {
-                    let addr = "127.0.0.1:0".parse::<SocketAddrV4>().unwrap();
-                    socket.join_multicast_v4(self.multicast_addr.ip(), addr.ip())?;
-                    error!(
-                        "Did you set the environment variable GZ_IP with a correct IP address? "
-                    );
-                    error!("  [{}] seems an invalid local IP address.", net_iface);
-                    error!("  Using 127.0.0.1 as hostname.");
-                    self.host_addr = *addr.ip();
-                } else {
-                    error!(
-                        "Failed to join multicast group [{}] on interface [{}].",
-                        self.multicast_addr.ip(),
-                        net_iface
-                    );
-                }
-            } else {
-                debug!(
-                    "Joining multicast group [{}] on interface [{}].",
-                    self.multicast_addr.ip(),
-                    net_iface
-                );
+
+    /// Send the BYE message and wait until the discovery task is finished.
+    pub(crate) async fn stop(&mut self) {
+        if let Some(sender) = self.msg_sender.take() {
+            if let Err(err) = sender.send(self.bye_msg()) {
+                debug!("Failed to send bye message: {}", err);
             }
         }
+        if let Some(handle) = self.handle.take() {
+            if let Err(err) = handle.await {
+                error!("Discovery task failed: {}", err);
+            }
+        }
+    }
 
-        // Ipv4Addr::UNSPECIFIED.into()
-        // Ipv4Addr::new(0, 0, 0, 0).into()
-        // let address = SocketAddr::new(IpAddr::V4(self.host_addr), self.multicast_addr.port());
-        let address = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), self.multicast_addr.port());
-        socket.bind(&address.into())?;
-
-        let socket = UdpSocket::from_std(socket.into())?;
-        self.socket = Some(Arc::new(socket));
-
-        Ok(())
+    fn bye_msg(&self) -> SendMsg {
+        SendMsg {
+            destination_type: DestinationType::All,
+            discovery_type: DiscoveryType::Bye,
+            discovery_publisher: DiscoveryPublisher {
+                process_uuid: self.p_uuid.clone(),
+                ..Default::default()
+            },
+        }
+    }
+    fn create_backend(&mut self) -> Result<Arc<dyn DiscoveryBackend>> {
+        let port = self.multicast_addr.port();
+        let backend: Arc<dyn DiscoveryBackend> = match &self.backend_type {
+            DiscoveryBackendType::Multicast => Arc::new(MulticastBackend::new(
+                self.multicast_addr,
+                &self.host_interfaces,
+                &mut self.host_addr,
+            )?),
+            DiscoveryBackendType::Static(path) => Arc::new(StaticBackend::new(path, port)?),
+            DiscoveryBackendType::InMemory => Arc::new(InMemoryBackend::new(port)),
+        };
+        Ok(backend)
     }
 }
 
 impl Drop for Discovery {
     fn drop(&mut self) {
         if let Some(sender) = self.msg_sender.as_ref() {
-            if let Err(err) = sender.send(SendMsg {
-                destination_type: DestinationType::All,
-                discovery_type: DiscoveryType::Bye,
-                discovery_publisher: DiscoveryPublisher {
-                    process_uuid: self.p_uuid.clone(),
-                    ..Default::default()
-                },
-            }) {
+            if let Err(err) = sender.send(self.bye_msg()) {
                 debug!("Failed to send bye message: {}", err);
             }
         }
@@ -458,7 +503,7 @@ impl Drop for Discovery {
 pub(super) struct DiscoveryInner {
     version: u32,
     p_uuid: String,
-    socket: Arc<UdpSocket>,
+    backend: Arc<dyn DiscoveryBackend>,
     host_interfaces: Vec<Ipv4Addr>,
     multicast_addr: SocketAddrV4,
     relay_addrs: Arc<Mutex<HashSet<SocketAddrV4>>>,
@@ -472,13 +517,14 @@ pub(super) struct DiscoveryInner {
     // Silence interval value (ms.).
     silence_interval: u64,
     verbose: bool,
+    ipc_address: Option<String>,
 }
 
 impl DiscoveryInner {
     pub(super) fn new(
         version: u32,
         p_uuid: String,
-        socket: Arc<UdpSocket>,
+        backend: Arc<dyn DiscoveryBackend>,
         host_interfaces: Vec<Ipv4Addr>,
         multicast_addr: SocketAddrV4,
         relay_addrs: Arc<Mutex<HashSet<SocketAddrV4>>>,
@@ -493,7 +539,7 @@ impl DiscoveryInner {
         DiscoveryInner {
             version,
             p_uuid,
-            socket,
+            backend,
             host_interfaces,
             multicast_addr,
             relay_addrs,
@@ -505,6 +551,7 @@ impl DiscoveryInner {
             unregistration_cb,
             silence_interval: DEF_SILENCE_INTERVAL,
             verbose,
+            ipc_address: None,
         }
     }
     fn is_local_ip(&self, from_ip: Ipv4Addr) -> bool {
@@ -584,9 +631,12 @@ impl DiscoveryInner {
             v
         };
 
-        // Remove publishers related to inactive processes.
+        // Remove publishers and subscribers related to inactive processes.
         let disconnect_processes = {
             let mut store = self.discovery_store.lock().unwrap();
+            for process_uuid in &inactive_processes {
+                let _ = store.del_subscribers_by_process(process_uuid);
+            }
             inactive_processes
                 .into_iter()
                 .filter(|process_uuid| {
@@ -623,6 +673,11 @@ impl DiscoveryInner {
         if self.p_uuid == msg.process_uuid {
             return Ok(());
         }
+        // Simulate a peer that went silent.
+        #[cfg(feature = "fault-injection")]
+        if crate::fault::is_silent(&msg.process_uuid) {
+            return Ok(());
+        }
         trace!("Received [{}] from [{}].", msg.r#type, msg.process_uuid);
 
         // Forwarding summary:
@@ -690,6 +745,14 @@ impl DiscoveryInner {
                     }
                 }
 
+                // Remember how to reach the process through IPC.
+                if let Some(address) = ipc_address(msg.header.as_ref()) {
+                    self.discovery_store
+                        .lock()
+                        .unwrap()
+                        .set_ipc_address(&msg.process_uuid, address);
+                }
+
                 // Register an advertised address for the topic.
                 match self
                     .discovery_store
@@ -712,6 +775,16 @@ impl DiscoveryInner {
                 if let Some(DiscoveryDiscContents::Sub(subscriber)) = &msg.disc_contents {
                     let recv_topic = &subscriber.topic;
 
+                    // Remember that the process is interested in the topic.
+                    {
+                        let mut store = self.discovery_store.lock().unwrap();
+                        let _ = store.add_subscriber(DiscoveryPublisher {
+                            topic: recv_topic.to_string(),
+                            process_uuid: msg.process_uuid.clone(),
+                            ..Default::default()
+                        });
+                    }
+
                     // Get registered publishers from the same process.
                     let publishers = {
                         let mut v = vec![];
@@ -737,11 +810,23 @@ impl DiscoveryInner {
                 }
             }
             DiscoveryType::NewConnection => {
+                {
+                    let mut store = self.discovery_store.lock().unwrap();
+                    let _ = store.add_subscriber(publisher.clone());
+                }
                 if let Some(cb) = self.registration_cb.lock().unwrap().as_ref() {
                     cb(publisher.clone());
                 }
             }
             DiscoveryType::EndConnection => {
+                {
+                    let mut store = self.discovery_store.lock().unwrap();
+                    let _ = store.del_subscriber_by_node(
+                        &publisher.topic,
+                        &publisher.process_uuid,
+                        &publisher.node_uuid,
+                    );
+                }
                 if let Some(cb) = self.unregistration_cb.lock().unwrap().as_ref() {
                     cb(publisher.clone());
                 }
@@ -768,6 +853,7 @@ impl DiscoveryInner {
                     if let Err(e) = store.del_publishers_by_process(&msg.process_uuid) {
                         debug!("Failed to remove publisher: {}", e);
                     }
+                    let _ = store.del_subscribers_by_process(&msg.process_uuid);
                 }
             }
             DiscoveryType::Unadvertise => {
@@ -821,6 +907,11 @@ impl DiscoveryInner {
             | DiscoveryType::EndConnection => {
                 let disc_contents = DiscoveryDiscContents::Pub(publisher.clone());
                 discovery_msg.disc_contents = Some(disc_contents);
+                if discovery_type == DiscoveryType::Advertise {
+                    if let Some(address) = self.ipc_address.as_ref() {
+                        discovery_msg.header = Some(ipc_header(address));
+                    }
+                }
             }
             DiscoveryType::Subscribe => {
                 let subscriber = DiscoverySubscriber {
@@ -878,7 +969,7 @@ impl DiscoveryInner {
 
         if let Ok((buffer, total_size)) = discovery_msg_encode(&msg) {
             for sock_addr in addrs {
-                match self.socket.send_to(&buffer, sock_addr).await {
+                match self.backend.send_to(&buffer, sock_addr).await {
                     Ok(sent) if sent != total_size => {
                         eprintln!("Exception sending a unicast message:");
                         eprintln!("  Return value: {}", sent);
@@ -900,9 +991,7 @@ impl DiscoveryInner {
     }
     async fn send_multicast(&self, msg: &DiscoveryMsg) -> Result<()> {
         if let Ok((buffer, total_size)) = discovery_msg_encode(&msg) {
-            // TODO: Support multiple Sockets.
-            // for sock in self.sockets.iter() { }
-            match self.socket.send_to(&buffer, self.multicast_addr).await {
+            match self.backend.send_multicast(&buffer).await {
                 Ok(sent) if sent != total_size => {
                     eprintln!(
                         "Exception sending a multicast message: {}",
@@ -924,6 +1013,27 @@ impl DiscoveryInner {
     }
 }
 
+// Header carrying our IPC endpoint. Other implementations ignore it.
+fn ipc_header(address: &str) -> msgs::Header {
+    msgs::Header {
+        stamp: None,
+        data: vec![msgs::header::Map {
+            key: IPC_HEADER_KEY.to_string(),
+            value: vec![address.to_string()],
+        }],
+    }
+}
+
+fn ipc_address(header: Option<&msgs::Header>) -> Option<&str> {
+    header?
+        .data
+        .iter()
+        .find(|m| m.key == IPC_HEADER_KEY)?
+        .value
+        .first()
+        .map(|s| s.as_str())
+}
+
 #[cfg(test)]
 mod tests {
     use once_cell::sync::Lazy;
@@ -1145,9 +1255,9 @@ mod tests {
     // an unadvertise.
     #[tokio::test]
     async fn test_unadvertise() {
-        tracing_subscriber::fmt()
+        let _ = tracing_subscriber::fmt()
             .with_max_level(tracing::Level::DEBUG)
-            .init();
+            .try_init();
 
         let mut discovery1 = Discovery::new(&P_UUID1, IP, MSG_PORT, true);
         let mut discovery2 = Discovery::new(&P_UUID2, IP, MSG_PORT, true);
@@ -1192,9 +1302,9 @@ mod tests {
     // sending a BYE message (discovery object out of scope).
     #[tokio::test]
     async fn test_bye() {
-        tracing_subscriber::fmt()
+        let _ = tracing_subscriber::fmt()
             .with_max_level(tracing::Level::DEBUG)
-            .init();
+            .try_init();
 
         let mut discovery1 = Discovery::new(&P_UUID1, IP, MSG_PORT, true);
         let mut discovery2 = Discovery::new(&P_UUID2, IP, MSG_PORT, true);
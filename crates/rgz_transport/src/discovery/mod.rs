@@ -1,7 +1,9 @@
+mod backend;
 mod discovery;
 mod types;
 mod store;
 
+pub use backend::DiscoveryBackendType;
 pub(crate) use discovery::Discovery;
 pub(crate) use types::*;
 pub(crate) use store::DiscoveryStore;
@@ -27,6 +29,9 @@ const DEF_SILENCE_INTERVAL: u64 = 3000;
 /// Timeout used for receiving messages (ms.).
 const TIMEOUT: i32 = 250;
 
+/// Header key announcing the IPC endpoint of a process.
+const IPC_HEADER_KEY: &str = "rgz_ipc";
+
 /// Get the discovery protocol version.
 fn version() -> u32 {
     let gz_stats = env::var("GZ_TRANSPORT_TOPIC_STATISTICS")
@@ -6,12 +6,19 @@ use crate::discovery::{DiscoveryPubType, DiscoveryPublisher};
 
 pub(crate) struct DiscoveryStore {
     discovery_publishers: HashMap<String, HashMap<String, Vec<DiscoveryPublisher>>>,
+    // Subscribers use the same layout as publishers (topic -> process UUID).
+    // An empty node UUID means that only the process interest is known.
+    discovery_subscribers: HashMap<String, HashMap<String, Vec<DiscoveryPublisher>>>,
+    // IPC endpoints announced by the processes on this host (process UUID -> endpoint).
+    ipc_addresses: HashMap<String, String>,
 }
 
 impl DiscoveryStore {
     pub(super) fn new() -> Self {
         Self {
             discovery_publishers: HashMap::new(),
+            discovery_subscribers: HashMap::new(),
+            ipc_addresses: HashMap::new(),
         }
     }
 
@@ -59,6 +66,7 @@ impl DiscoveryStore {
 
     /// Remove all the publishers associated to a given process.
     pub fn del_publishers_by_process(&mut self, p_uuid: &str) -> Result<()> {
+        self.ipc_addresses.remove(p_uuid);
         let mut del_check = false;
         let mut topics_to_remove = Vec::new();
         for (topic, processes) in &mut self.discovery_publishers {
@@ -78,6 +86,17 @@ impl DiscoveryStore {
         Ok(())
     }
 
+    /// Remember the IPC endpoint announced by a process.
+    pub fn set_ipc_address(&mut self, p_uuid: &str, address: &str) {
+        self.ipc_addresses
+            .insert(p_uuid.to_string(), address.to_string());
+    }
+
+    /// Get the IPC endpoint announced by a process.
+    pub fn ipc_address(&self, p_uuid: &str) -> Option<&str> {
+        self.ipc_addresses.get(p_uuid).map(|s| s.as_str())
+    }
+
     /// Return if there is any publisher stored for the given topic.
     pub fn has_topic(&self, topic: &str) -> bool {
         self.discovery_publishers.contains_key(topic)
@@ -214,6 +233,97 @@ impl DiscoveryStore {
             .collect()
     }
 
+    /// Add a subscriber associated to a given topic and node UUID.
+    pub fn add_subscriber(&mut self, discovery_subscriber: DiscoveryPublisher) -> Result<()> {
+        let processes = self
+            .discovery_subscribers
+            .entry(discovery_subscriber.topic.to_string())
+            .or_default();
+        let subscribers = processes
+            .entry(discovery_subscriber.process_uuid.to_string())
+            .or_default();
+
+        if discovery_subscriber.node_uuid.is_empty() {
+            // The process interest is already known.
+            if !subscribers.is_empty() {
+                bail!("Subscriber already exists");
+            }
+        } else {
+            if subscribers
+                .iter()
+                .any(|s| s.node_uuid == discovery_subscriber.node_uuid)
+            {
+                bail!("Subscriber already exists");
+            }
+            // The node replaces the process placeholder.
+            subscribers.retain(|s| !s.node_uuid.is_empty());
+        }
+        subscribers.push(discovery_subscriber);
+
+        Ok(())
+    }
+
+    /// Remove a subscriber associated to a given topic and UUID pair, along
+    /// with the placeholder of its process.
+    pub fn del_subscriber_by_node(&mut self, topic: &str, p_uuid: &str, n_uuid: &str) -> Result<()> {
+        if let Some(processes) = self.discovery_subscribers.get_mut(topic) {
+            if let Some(subscribers) = processes.get_mut(p_uuid) {
+                if !subscribers
+                    .iter()
+                    .any(|s| s.node_uuid == n_uuid || s.node_uuid.is_empty())
+                {
+                    bail!("Subscriber not found");
+                }
+                subscribers.retain(|s| s.node_uuid != n_uuid && !s.node_uuid.is_empty());
+                if subscribers.is_empty() {
+                    processes.remove(p_uuid);
+                }
+                if processes.is_empty() {
+                    self.discovery_subscribers.remove(topic);
+                }
+                return Ok(());
+            }
+        }
+        bail!("Subscriber not found");
+    }
+
+    /// Remove all the subscribers associated to a given process.
+    pub fn del_subscribers_by_process(&mut self, p_uuid: &str) -> Result<()> {
+        let mut del_check = false;
+        for processes in self.discovery_subscribers.values_mut() {
+            if processes.remove(p_uuid).is_some() {
+                del_check = true;
+            }
+        }
+        if !del_check {
+            bail!("Subscriber not found");
+        }
+        self.discovery_subscribers
+            .retain(|_, processes| !processes.is_empty());
+        Ok(())
+    }
+
+    /// Return if there is any subscriber stored for the given topic and,
+    /// optionally, message type.
+    pub fn has_subscribers(&self, topic: &str, msg_type: Option<&str>) -> bool {
+        self.subscribers(topic).into_iter().any(|s| match msg_type {
+            Some(msg_type) => match s.pub_type {
+                Some(DiscoveryPubType::MsgPub(ref msg_pub)) => msg_pub.msg_type == msg_type,
+                // The type of a process placeholder is unknown.
+                _ => true,
+            },
+            None => true,
+        })
+    }
+
+    /// Get the subscribers stored for a given topic.
+    pub fn subscribers(&self, topic: &str) -> Vec<&DiscoveryPublisher> {
+        match self.discovery_subscribers.get(topic) {
+            Some(subscribers) => subscribers.values().flatten().collect(),
+            None => vec![],
+        }
+    }
+
     /// Print all the information for debugging purposes.
     pub fn print(&self) {
         let mut collect: Vec<_> = self.discovery_publishers.iter().collect();
@@ -522,6 +632,26 @@ mod tests {
         assert_eq!(pubs.len(), 1);
     }
 
+    #[test]
+    fn test_ipc_address() {
+        let mut store = DiscoveryStore::new();
+        let pub1 = create_msg_publisher(
+            "topic1",
+            "addr1",
+            "p_uuid1",
+            "n_uuid1",
+            DiscoveryScope::All,
+            "gz.msgs.StringMsg",
+        );
+        store.add_publisher(pub1).unwrap();
+        store.set_ipc_address("p_uuid1", "ipc:///tmp/p_uuid1.ipc");
+        assert_eq!(store.ipc_address("p_uuid1"), Some("ipc:///tmp/p_uuid1.ipc"));
+        assert_eq!(store.ipc_address("p_uuid2"), None);
+
+        store.del_publishers_by_process("p_uuid1").unwrap();
+        assert_eq!(store.ipc_address("p_uuid1"), None);
+    }
+
     #[test]
     fn test_publishers_by_process() {
         let mut store = DiscoveryStore::new();
@@ -618,6 +748,95 @@ mod tests {
         assert_eq!(topics.len(), 2);
     }
 
+    #[test]
+    fn test_add_subscriber() {
+        let mut store = DiscoveryStore::new();
+        let placeholder = DiscoveryPublisher {
+            topic: "topic1".to_string(),
+            process_uuid: "p_uuid1".to_string(),
+            ..Default::default()
+        };
+        let sub1 = create_msg_publisher(
+            "topic1",
+            "addr1",
+            "p_uuid1",
+            "n_uuid1",
+            DiscoveryScope::All,
+            "gz.msgs.StringMsg",
+        );
+
+        store.add_subscriber(placeholder.clone()).unwrap();
+        assert_eq!(store.subscribers("topic1").len(), 1);
+        assert!(store.has_subscribers("topic1", Some("gz.msgs.Int32")));
+
+        // The node replaces the placeholder of its process.
+        store.add_subscriber(sub1.clone()).unwrap();
+        assert_eq!(store.subscribers("topic1"), vec![&sub1]);
+        assert!(store.has_subscribers("topic1", Some("gz.msgs.StringMsg")));
+        assert!(!store.has_subscribers("topic1", Some("gz.msgs.Int32")));
+        assert!(!store.has_subscribers("topic2", None));
+
+        let check = store.add_subscriber(sub1.clone());
+        assert_eq!(check.unwrap_err().to_string(), "Subscriber already exists");
+        let check = store.add_subscriber(placeholder);
+        assert_eq!(check.unwrap_err().to_string(), "Subscriber already exists");
+    }
+
+    #[test]
+    fn test_del_subscribers() {
+        let mut store = DiscoveryStore::new();
+        let sub1 = create_msg_publisher(
+            "topic1",
+            "addr1",
+            "p_uuid1",
+            "n_uuid1",
+            DiscoveryScope::All,
+            "gz.msgs.StringMsg",
+        );
+        let sub2 = create_msg_publisher(
+            "topic2",
+            "addr1",
+            "p_uuid1",
+            "n_uuid2",
+            DiscoveryScope::All,
+            "gz.msgs.StringMsg",
+        );
+        let sub3 = create_msg_publisher(
+            "topic2",
+            "addr2",
+            "p_uuid2",
+            "n_uuid3",
+            DiscoveryScope::All,
+            "gz.msgs.StringMsg",
+        );
+        store.add_subscriber(sub1).unwrap();
+        store.add_subscriber(sub2).unwrap();
+        store.add_subscriber(sub3).unwrap();
+
+        let result = store.del_subscriber_by_node("topic1", "p_uuid1", "n_uuid1");
+        assert!(result.is_ok());
+        let result = store.del_subscriber_by_node("topic1", "p_uuid1", "n_uuid1");
+        assert!(result.is_err());
+        assert!(!store.has_subscribers("topic1", None));
+
+        let result = store.del_subscribers_by_process("p_uuid1");
+        assert!(result.is_ok());
+        let result = store.del_subscribers_by_process("p_uuid1");
+        assert!(result.is_err());
+        assert_eq!(store.subscribers("topic2").len(), 1);
+
+        // The placeholder of a process goes with its nodes.
+        let placeholder = DiscoveryPublisher {
+            topic: "topic1".to_string(),
+            process_uuid: "p_uuid1".to_string(),
+            ..Default::default()
+        };
+        store.add_subscriber(placeholder).unwrap();
+        let result = store.del_subscriber_by_node("topic1", "p_uuid1", "n_uuid1");
+        assert!(result.is_ok());
+        assert!(!store.has_subscribers("topic1", None));
+    }
+
     #[test]
     fn test_print() {
         let store = DiscoveryStore::new();
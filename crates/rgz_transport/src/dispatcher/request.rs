@@ -36,6 +36,20 @@ impl PendingRequest {
         self.request_message.is_none()
     }
 
+    /// Return if the request waits for the type names of the service.
+    pub fn unresolved(&self) -> bool {
+        self.req_type.as_deref() == Some("") || self.res_type.as_deref() == Some("")
+    }
+
+    pub fn resolve_types(&mut self, req_type: &str, res_type: &str) {
+        self.req_type = Some(req_type.to_string());
+        self.res_type = Some(res_type.to_string());
+        if let Some(request_message) = self.request_message.as_mut() {
+            request_message.req_type = req_type.to_string();
+            request_message.res_type = res_type.to_string();
+        }
+    }
+
     pub fn message(&mut self) -> Option<RequestMessage> {
         self.request_message.take()
     }
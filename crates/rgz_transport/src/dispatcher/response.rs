@@ -13,6 +13,9 @@ pub(crate) struct ResponseDispatcher {
     res_type: Option<String>,
     sender: Option<oneshot::Sender<ReplyMessage>>,
     remote_flag: bool,
+    // Kept to resend the request if the replier disappears.
+    request_message: Option<RequestMessage>,
+    replier_p_uuid: Option<String>,
     created: std::time::Instant,
     done_flag: bool,
 }
@@ -31,11 +34,40 @@ impl ResponseDispatcher {
             res_type: Some(request_message.res_type.clone()),
             sender,
             remote_flag,
+            request_message: None,
+            replier_p_uuid: None,
             created: std::time::Instant::now(),
             done_flag: false,
         }
     }
 
+    /// Remember the remote process serving the request of a local requester.
+    pub fn with_replier(mut self, request_message: RequestMessage, p_uuid: &str) -> Self {
+        self.request_message = Some(request_message);
+        self.replier_p_uuid = Some(p_uuid.to_string());
+        self
+    }
+
+    /// Return if the request waits for the given replier address or process.
+    pub fn waits_for(&self, address: &str, p_uuid: &str) -> bool {
+        if self.done_flag {
+            return false;
+        }
+        let replier_address = self
+            .request_message
+            .as_ref()
+            .and_then(|m| m.replier_address.as_deref());
+        (!address.is_empty() && replier_address == Some(address))
+            || (!p_uuid.is_empty() && self.replier_p_uuid.as_deref() == Some(p_uuid))
+    }
+
+    pub fn into_request(self) -> Option<(RequestMessage, oneshot::Sender<ReplyMessage>)> {
+        match (self.request_message, self.sender) {
+            (Some(request_message), Some(sender)) => Some((request_message, sender)),
+            _ => None,
+        }
+    }
+
     pub fn dispatch(&mut self, reply_message: ReplyMessage) -> Result<()> {
         if let Some(sender) = self.sender.take() {
             if let Err(err) = sender.send(reply_message) {
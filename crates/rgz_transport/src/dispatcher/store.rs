@@ -136,6 +136,28 @@ where
     }
 }
 
+impl DispatcherStore<ResponseDispatcher> {
+    /// Remove the requests waiting for a replier that disappeared.
+    pub fn remove_by_replier(&mut self, address: &str, p_uuid: &str) -> Vec<ResponseDispatcher> {
+        let mut removed = vec![];
+        self.topics.iter_mut().for_each(|(_, nodes)| {
+            nodes.iter_mut().for_each(|(_, dispatchers)| {
+                let uuids: Vec<String> = dispatchers
+                    .iter()
+                    .filter(|(_, d)| d.waits_for(address, p_uuid))
+                    .map(|(uuid, _)| uuid.to_string())
+                    .collect();
+                for uuid in uuids {
+                    removed.extend(dispatchers.remove(&uuid));
+                }
+            });
+            nodes.retain(|_, dispatchers| !dispatchers.is_empty());
+        });
+        self.topics.retain(|_, nodes| !nodes.is_empty());
+        removed
+    }
+}
+
 pub(crate) trait DeleteFunction {
     fn del_by_process(&mut self, process_uuid: &str);
 }
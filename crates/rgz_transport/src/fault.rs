@@ -0,0 +1,263 @@
+//! Fault injection for testing nodes against bad networks.
+//!
+//! The faults apply to the messages and requests received from other
+//! processes, and to the discovery messages of silenced peers. Configure them
+//! with `set()` or with the `RGZ_FAULT_INJECTION` environment variable, e.g.
+//!
+//! ```text
+//! RGZ_FAULT_INJECTION="/imu:drop=0.1,latency_ms=20;*:jitter_ms=5;silent=<p_uuid>;seed=42"
+//! ```
+//!
+//! Entries are separated by `;`. `<topic>:<faults>` configures a topic, `*`
+//! the topics without their own entry. The faults are `latency_ms`,
+//! `jitter_ms`, `drop`, `reorder` and `duplicate`, the last three being
+//! probabilities.
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use tracing::{error, trace};
+
+/// Environment variable read when `set()` has not been called.
+pub const FAULT_INJECTION_ENV: &str = "RGZ_FAULT_INJECTION";
+
+/// Faults applied to the messages of a topic.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TopicFaults {
+    pub latency: Duration,
+    /// Random extra latency, up to this value.
+    pub jitter: Duration,
+    /// Probability of dropping a message.
+    pub drop: f64,
+    /// Probability of delivering a message after the ones that follow it.
+    pub reorder: f64,
+    /// Probability of delivering a message twice.
+    pub duplicate: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FaultConfig {
+    /// Faults per topic. The keys are plain or fully qualified topic names.
+    pub topics: HashMap<String, TopicFaults>,
+    /// Faults of the topics without their own entry.
+    pub default: Option<TopicFaults>,
+    /// Process UUIDs whose discovery messages are ignored, as if they went silent.
+    pub silent_peers: HashSet<String>,
+    /// Seed of the random generator, for reproducible runs.
+    pub seed: Option<u64>,
+}
+
+impl FaultConfig {
+    pub fn from_env() -> Result<Option<Self>> {
+        match env::var(FAULT_INJECTION_ENV) {
+            Ok(value) if !value.trim().is_empty() => Ok(Some(Self::parse(&value)?)),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        let mut config = FaultConfig::default();
+        for entry in value.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+            if let Some(p_uuid) = entry.strip_prefix("silent=") {
+                config.silent_peers.insert(p_uuid.to_string());
+            } else if let Some(seed) = entry.strip_prefix("seed=") {
+                config.seed = Some(seed.parse()?);
+            } else if let Some((topic, faults)) = entry.rsplit_once(':') {
+                let faults = parse_topic_faults(faults)?;
+                if topic == "*" {
+                    config.default = Some(faults);
+                } else {
+                    config.topics.insert(topic.to_string(), faults);
+                }
+            } else {
+                bail!("Invalid fault injection entry [{}]", entry);
+            }
+        }
+        Ok(config)
+    }
+
+    fn topic_faults(&self, topic: &str) -> Option<&TopicFaults> {
+        self.topics
+            .iter()
+            .find(|(name, _)| {
+                // Fully qualified names look like `@partition@/topic`.
+                topic == name.as_str() || topic.ends_with(&format!("@{}", name))
+            })
+            .map(|(_, faults)| faults)
+            .or(self.default.as_ref())
+    }
+}
+
+fn parse_topic_faults(value: &str) -> Result<TopicFaults> {
+    let mut faults = TopicFaults::default();
+    for fault in value.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+        let (key, value) = match fault.split_once('=') {
+            Some(key_value) => key_value,
+            None => bail!("Invalid fault [{}]", fault),
+        };
+        match key {
+            "latency_ms" => faults.latency = Duration::from_millis(value.parse()?),
+            "jitter_ms" => faults.jitter = Duration::from_millis(value.parse()?),
+            "drop" => faults.drop = value.parse()?,
+            "reorder" => faults.reorder = value.parse()?,
+            "duplicate" => faults.duplicate = value.parse()?,
+            _ => bail!("Unknown fault [{}]", key),
+        }
+    }
+    Ok(faults)
+}
+
+struct FaultState {
+    config: Option<FaultConfig>,
+    rng: u64,
+}
+
+static STATE: Lazy<Mutex<FaultState>> = Lazy::new(|| {
+    let config = FaultConfig::from_env().unwrap_or_else(|err| {
+        error!("Failed to parse {}: {}", FAULT_INJECTION_ENV, err);
+        None
+    });
+    Mutex::new(FaultState::new(config))
+});
+
+impl FaultState {
+    fn new(config: Option<FaultConfig>) -> Self {
+        let seed = config
+            .as_ref()
+            .and_then(|c| c.seed)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().as_u64_pair().0);
+        FaultState {
+            config,
+            // xorshift does not accept a zero state.
+            rng: seed.max(1),
+        }
+    }
+
+    // Uniform value in [0, 1).
+    fn random(&mut self) -> f64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Replace the fault configuration of this process. `None` disables the faults.
+pub fn set(config: Option<FaultConfig>) {
+    *STATE.lock().unwrap() = FaultState::new(config);
+}
+
+pub fn config() -> Option<FaultConfig> {
+    STATE.lock().unwrap().config.clone()
+}
+
+// Return if the discovery messages of a process must be ignored.
+pub(crate) fn is_silent(p_uuid: &str) -> bool {
+    let state = STATE.lock().unwrap();
+    state
+        .config
+        .as_ref()
+        .is_some_and(|c| c.silent_peers.contains(p_uuid))
+}
+
+// Deliver an event of a topic after applying its faults.
+pub(crate) fn inject<T, F>(topic: &str, event: T, deliver: F)
+where
+    T: Clone + Send + 'static,
+    F: Fn(T) + Clone + Send + 'static,
+{
+    let delays = {
+        let mut state = STATE.lock().unwrap();
+        let faults = match state.config.as_ref().and_then(|c| c.topic_faults(topic)) {
+            Some(faults) => faults.clone(),
+            None => {
+                drop(state);
+                deliver(event);
+                return;
+            }
+        };
+        if state.random() < faults.drop {
+            trace!("Dropped a message of [{}]", topic);
+            return;
+        }
+        let copies = if state.random() < faults.duplicate { 2 } else { 1 };
+        (0..copies)
+            .map(|_| {
+                let mut delay = faults.latency + faults.jitter.mul_f64(state.random());
+                if state.random() < faults.reorder {
+                    // Let the following messages overtake this one.
+                    delay += faults.latency + faults.jitter + Duration::from_millis(10);
+                }
+                delay
+            })
+            .collect::<Vec<Duration>>()
+    };
+
+    for delay in delays {
+        let event = event.clone();
+        let deliver = deliver.clone();
+        if delay.is_zero() {
+            deliver(event);
+        } else if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                tokio::time::sleep(delay).await;
+                deliver(event);
+            });
+        } else {
+            thread::spawn(move || {
+                thread::sleep(delay);
+                deliver(event);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config =
+            FaultConfig::parse("/imu:drop=0.1,latency_ms=20; *:jitter_ms=5;silent=abc;seed=42")
+                .unwrap();
+        assert_eq!(config.seed, Some(42));
+        assert!(config.silent_peers.contains("abc"));
+        let imu = config.topic_faults("@/host:user@/imu").unwrap();
+        assert_eq!(imu.drop, 0.1);
+        assert_eq!(imu.latency, Duration::from_millis(20));
+        let other = config.topic_faults("@/host:user@/other").unwrap();
+        assert_eq!(other.jitter, Duration::from_millis(5));
+
+        assert!(FaultConfig::parse("/imu:loss=0.1").is_err());
+        assert!(FaultConfig::parse("nonsense").is_err());
+    }
+
+    #[test]
+    fn test_inject() {
+        let (sender, receiver) = mpsc::channel::<u32>();
+        let deliver = move |i| sender.send(i).unwrap();
+
+        let config = "/dropped:drop=1;/twice:duplicate=1;/late:latency_ms=30";
+        set(Some(FaultConfig::parse(config).unwrap()));
+        inject("/dropped", 1, deliver.clone());
+        inject("/twice", 2, deliver.clone());
+        inject("/late", 3, deliver.clone());
+        inject("/untouched", 4, deliver.clone());
+        assert!(!is_silent("abc"));
+        set(None);
+
+        let timeout = Duration::from_millis(200);
+        assert_eq!(receiver.recv_timeout(timeout).unwrap(), 2);
+        assert_eq!(receiver.recv_timeout(timeout).unwrap(), 2);
+        assert_eq!(receiver.recv_timeout(timeout).unwrap(), 4);
+        assert_eq!(receiver.recv_timeout(timeout).unwrap(), 3);
+        assert!(receiver.recv_timeout(timeout).is_err());
+    }
+}
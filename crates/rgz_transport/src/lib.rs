@@ -1,7 +1,20 @@
+mod compression;
+mod context;
 mod discovery;
 mod dispatcher;
+#[cfg(feature = "fault-injection")]
+pub mod fault;
 mod node;
+mod security;
 mod transport;
 mod utils;
 
-pub use node::{AdvertiseOptions, Node, NodeOptions, Publisher};
+pub use compression::Compression;
+pub use context::{init, shutdown, ShutdownGuard, TransportContext};
+pub use discovery::DiscoveryBackendType;
+pub use node::{
+    AdvertiseOptions, GraphEvent, LatencyHistogram, Node, NodeOptions, Publisher,
+    ServiceEndpoint, ServiceMetrics, ServiceSelection, StampClock, Timer, TopicEndpoint,
+    TopicMetrics, TransportMetrics, LATENCY_BUCKETS_MS,
+};
+pub use security::{CurveKeys, PlainCredentials};
@@ -0,0 +1,207 @@
+use std::future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rgz_msgs as msgs;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Time source of a node.
+#[derive(Debug, Clone)]
+pub(crate) enum Clock {
+    /// Time since the UNIX epoch.
+    Wall,
+    /// Simulation time received from a clock topic.
+    Sim(watch::Receiver<Duration>),
+}
+
+impl Clock {
+    pub(crate) fn now(&self) -> Duration {
+        match self {
+            Clock::Wall => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default(),
+            Clock::Sim(receiver) => *receiver.borrow(),
+        }
+    }
+
+    /// Wait until the clock reaches the deadline.
+    ///
+    /// The simulation time only moves when a clock message arrives, so the
+    /// wait lasts as long as the simulation is paused.
+    pub(crate) async fn sleep_until(&mut self, deadline: Duration) {
+        self.sleep_until_or_back(Duration::ZERO, deadline).await;
+    }
+
+    /// Like `sleep_until()`, but it returns false as soon as the clock goes
+    /// back before `since`, e.g. when the simulation is reset.
+    async fn sleep_until_or_back(&mut self, since: Duration, deadline: Duration) -> bool {
+        match self {
+            Clock::Wall => {
+                let now = Clock::Wall.now();
+                tokio::time::sleep(deadline.saturating_sub(now)).await;
+                true
+            }
+            Clock::Sim(receiver) => loop {
+                let now = *receiver.borrow_and_update();
+                if now < since {
+                    return false;
+                }
+                if now >= deadline {
+                    return true;
+                }
+                if receiver.changed().await.is_err() {
+                    // The clock topic is gone, the time never moves again.
+                    future::pending::<()>().await;
+                }
+            },
+        }
+    }
+}
+
+/// Convert the simulation time of a clock message.
+pub(crate) fn sim_time(clock: &msgs::Clock) -> Option<Duration> {
+    let sim = clock.sim.as_ref()?;
+    if sim.sec < 0 || sim.nsec < 0 {
+        return None;
+    }
+    Some(Duration::new(sim.sec as u64, sim.nsec as u32))
+}
+
+/// Periodic callback created by `Node::create_timer()`. Dropping it stops the timer.
+#[derive(Debug)]
+pub struct Timer {
+    handle: JoinHandle<()>,
+}
+
+impl Timer {
+    pub(crate) fn start<F>(mut clock: Clock, period: Duration, mut cb: F) -> Self
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            let mut last = clock.now();
+            let mut next = last.saturating_add(period);
+            loop {
+                if !clock.sleep_until_or_back(last, next).await {
+                    // The clock went back, start the period again.
+                    last = clock.now();
+                    next = last.saturating_add(period);
+                    continue;
+                }
+                cb();
+                last = clock.now();
+                next = next_tick(next, last, period);
+            }
+        });
+        Timer { handle }
+    }
+
+    pub fn cancel(&self) {
+        self.handle.abort();
+    }
+}
+
+// The deadline following `next`, skipping the periods missed after a jump of
+// the clock.
+fn next_tick(next: Duration, now: Duration, period: Duration) -> Duration {
+    let next = next.saturating_add(period);
+    if next > now || period.is_zero() {
+        return next;
+    }
+    let late = (now - next).as_nanos() % period.as_nanos();
+    let late = Duration::new((late / 1_000_000_000) as u64, (late % 1_000_000_000) as u32);
+    now.saturating_add(period - late)
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sim_clock() {
+        let (sender, receiver) = watch::channel(Duration::ZERO);
+        let mut clock = Clock::Sim(receiver);
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let t = ticks.clone();
+        let timer = Timer::start(clock.clone(), Duration::from_millis(10), move || {
+            t.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // The simulation is paused.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), 0);
+
+        for ms in [5, 10, 15, 20, 30] {
+            sender.send(Duration::from_millis(ms)).unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(ticks.load(Ordering::SeqCst), 3);
+        timer.cancel();
+
+        let deadline = Duration::from_secs(1);
+        let sleep = tokio::spawn(async move { clock.sleep_until(deadline).await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!sleep.is_finished());
+        sender.send(deadline).unwrap();
+        tokio::time::timeout(Duration::from_millis(100), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sim_clock_reset() {
+        let (sender, receiver) = watch::channel(Duration::from_secs(100));
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let t = ticks.clone();
+        let _timer = Timer::start(Clock::Sim(receiver), Duration::from_millis(10), move || {
+            t.fetch_add(1, Ordering::SeqCst);
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // A world reset, the timer must not wait for the old deadline.
+        for ms in [0, 5, 10] {
+            sender.send(Duration::from_millis(ms)).unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(ticks.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_next_tick() {
+        let ms = Duration::from_millis;
+        assert_eq!(next_tick(ms(10), ms(10), ms(10)), ms(20));
+        assert_eq!(next_tick(ms(10), ms(20), ms(10)), ms(30));
+        assert_eq!(next_tick(ms(10), ms(25), ms(10)), ms(30));
+        assert_eq!(next_tick(ms(10), ms(10), Duration::ZERO), ms(10));
+        // Neither a long jump nor a long period overflow.
+        let far = Duration::from_secs(u64::MAX / 6 * 3);
+        assert_eq!(next_tick(ms(10), far, ms(3)), far + ms(1));
+        assert_eq!(next_tick(ms(10), ms(10), Duration::MAX), Duration::MAX);
+        assert_eq!(
+            next_tick(Duration::MAX, Duration::MAX, ms(10)),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn test_sim_time() {
+        let clock = msgs::Clock {
+            sim: Some(msgs::Time { sec: 3, nsec: 500 }),
+            ..Default::default()
+        };
+        assert_eq!(sim_time(&clock), Some(Duration::new(3, 500)));
+        assert_eq!(sim_time(&msgs::Clock::default()), None);
+    }
+}
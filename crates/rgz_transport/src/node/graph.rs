@@ -0,0 +1,78 @@
+use crate::discovery::{DiscoveryPubType, DiscoveryPublisher};
+
+/// A topic endpoint (publisher or subscriber) seen through discovery.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TopicEndpoint {
+    pub topic: String,
+    pub msg_type: String,
+    pub address: String,
+    pub process_uuid: String,
+    pub node_uuid: String,
+}
+
+/// A service provider seen through discovery.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ServiceEndpoint {
+    pub topic: String,
+    pub request_type: String,
+    pub response_type: String,
+    pub address: String,
+    pub process_uuid: String,
+    pub node_uuid: String,
+}
+
+/// Changes in the transport graph, emitted by `Node::graph_events()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphEvent {
+    /// A remote publisher has been advertised.
+    PublisherAppeared(TopicEndpoint),
+    /// A remote publisher has been unadvertised.
+    PublisherDisappeared(TopicEndpoint),
+    /// A remote subscriber has connected to a publisher.
+    SubscriberRegistered(TopicEndpoint),
+    /// A remote subscriber has disconnected from a publisher.
+    SubscriberUnregistered(TopicEndpoint),
+    /// A remote service has been advertised.
+    ServiceAdvertised(ServiceEndpoint),
+    /// A remote service has been unadvertised.
+    ServiceRemoved(ServiceEndpoint),
+    /// A remote process said goodbye or has not been heard from within the
+    /// silence interval. All its endpoints are gone.
+    ProcessSilent(String),
+}
+
+impl From<&DiscoveryPublisher> for TopicEndpoint {
+    fn from(publisher: &DiscoveryPublisher) -> Self {
+        let msg_type = match publisher.pub_type {
+            Some(DiscoveryPubType::MsgPub(ref msg_pub)) => msg_pub.msg_type.to_string(),
+            _ => "".to_string(),
+        };
+        TopicEndpoint {
+            topic: publisher.topic.to_string(),
+            msg_type,
+            address: publisher.address.to_string(),
+            process_uuid: publisher.process_uuid.to_string(),
+            node_uuid: publisher.node_uuid.to_string(),
+        }
+    }
+}
+
+impl From<&DiscoveryPublisher> for ServiceEndpoint {
+    fn from(publisher: &DiscoveryPublisher) -> Self {
+        let (request_type, response_type) = match publisher.pub_type {
+            Some(DiscoveryPubType::SrvPub(ref srv_pub)) => (
+                srv_pub.request_type.to_string(),
+                srv_pub.response_type.to_string(),
+            ),
+            _ => ("".to_string(), "".to_string()),
+        };
+        ServiceEndpoint {
+            topic: publisher.topic.to_string(),
+            request_type,
+            response_type,
+            address: publisher.address.to_string(),
+            process_uuid: publisher.process_uuid.to_string(),
+            node_uuid: publisher.node_uuid.to_string(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Upper bounds of the request latency buckets (ms.).
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [1, 2, 5, 10, 50, 100, 500, 1000];
+
+/// Counters of a topic in this process.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TopicMetrics {
+    pub msgs_published: u64,
+    pub bytes_published: u64,
+    pub msgs_received: u64,
+    pub bytes_received: u64,
+    pub decode_failures: u64,
+    /// Messages dropped at the high water mark: the published ones a subscriber
+    /// had no room for, and the received ones that did not fit in the receive
+    /// buffer. Only the `zmtp` transport reports them, libzmq drops silently
+    /// and this stays at zero.
+    pub dropped: u64,
+    /// Published messages that failed with a send error.
+    pub send_errors: u64,
+}
+
+/// Histogram of request latencies.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LatencyHistogram {
+    /// Samples per bucket of `LATENCY_BUCKETS_MS`. The last one counts the slower requests.
+    pub buckets: [u64; LATENCY_BUCKETS_MS.len() + 1],
+    pub count: u64,
+    pub sum: Duration,
+    pub max: Duration,
+}
+
+impl LatencyHistogram {
+    pub(crate) fn record(&mut self, latency: Duration) {
+        let ms = latency.as_millis();
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound as u128)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum += latency;
+        self.max = self.max.max(latency);
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.sum / self.count as u32)
+    }
+}
+
+/// Counters of the requests sent to a service from this process.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServiceMetrics {
+    pub requests: u64,
+    /// Requests that timed out or could not be delivered.
+    pub failures: u64,
+    pub pending_requests: u64,
+    pub latency: LatencyHistogram,
+}
+
+/// Snapshot of the transport counters of this process.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransportMetrics {
+    pub topics: HashMap<String, TopicMetrics>,
+    pub services: HashMap<String, ServiceMetrics>,
+}
+
+impl TransportMetrics {
+    pub fn topic(&self, topic: &str) -> Option<&TopicMetrics> {
+        self.topics.get(topic)
+    }
+    pub fn service(&self, service: &str) -> Option<&ServiceMetrics> {
+        self.services.get(service)
+    }
+    pub(crate) fn topic_mut(&mut self, topic: &str) -> &mut TopicMetrics {
+        self.topics.entry(topic.to_string()).or_default()
+    }
+    pub(crate) fn service_mut(&mut self, service: &str) -> &mut ServiceMetrics {
+        self.services.entry(service.to_string()).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.mean(), None);
+
+        histogram.record(Duration::from_micros(500));
+        histogram.record(Duration::from_millis(7));
+        histogram.record(Duration::from_secs(3));
+        assert_eq!(histogram.buckets, [1, 0, 0, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(histogram.count, 3);
+        assert_eq!(histogram.max, Duration::from_secs(3));
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(3_007_500) / 3));
+    }
+}
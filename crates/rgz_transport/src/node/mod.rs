@@ -1,18 +1,27 @@
+mod clock;
+mod graph;
+mod metrics;
 mod node;
 mod options;
 mod shared;
 
+pub use clock::Timer;
+pub use graph::{GraphEvent, ServiceEndpoint, TopicEndpoint};
+pub use metrics::{
+    LatencyHistogram, ServiceMetrics, TopicMetrics, TransportMetrics, LATENCY_BUCKETS_MS,
+};
 pub use node::{Node, Publisher};
-pub use options::{AdvertiseOptions, NodeOptions};
+pub use options::{AdvertiseOptions, NodeOptions, ServiceSelection, StampClock};
+pub(crate) use shared::NodeShared;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
 use crate::discovery::DiscoveryPublisher;
 use crate::transport::{PublishMessage, ReplyMessage, RequestMessage};
 
-const DEFAULT_DISCOVERY_IP: &str = "239.255.0.7";
-const DEFAULT_MSG_DISC_PORT: u16 = 10317;
-const DEFAULT_SRV_DISC_PORT: u16 = 10318;
+pub(crate) const DEFAULT_DISCOVERY_IP: &str = "239.255.0.7";
+pub(crate) const DEFAULT_MSG_DISC_PORT: u16 = 10317;
+pub(crate) const DEFAULT_SRV_DISC_PORT: u16 = 10318;
 
 #[derive(Debug, Clone)]
 pub(crate) struct SubscribeArgs {
@@ -27,10 +36,14 @@ pub(crate) enum NodeEvent {
     AdvertiseService(DiscoveryPublisher, UnboundedSender<RequestMessage>),
     Subscribe(SubscribeArgs),
     Publish(PublishMessage),
+    PublishBatch(Vec<PublishMessage>),
     Request(RequestMessage, oneshot::Sender<ReplyMessage>),
     Reply(ReplyMessage),
+    GraphEvents(UnboundedSender<GraphEvent>),
+    Shutdown,
 }
 
+#[derive(Clone)]
 pub(crate) enum TransportEvent {
     Subscription(PublishMessage),
     Request(RequestMessage),
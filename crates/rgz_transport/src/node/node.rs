@@ -5,17 +5,25 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
 
 use anyhow::{bail, Result};
-use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::watch;
 use tokio::time::{timeout_at, Instant};
-use tracing::{error};
+use tracing::{error, info_span, Instrument};
 
+use crate::compression::CompressionRef;
 use crate::discovery::{
-    DiscoveryMsgPublisher, DiscoveryPubType, DiscoveryPublisher, DiscoverySrvPublisher,
+    DiscoveryMsgPublisher, DiscoveryPubType, DiscoveryPublisher, DiscoveryStore,
+    DiscoverySrvPublisher,
 };
+use crate::node::clock::{self, Clock, Timer};
 use crate::node::shared::NodeShared;
-use crate::node::{AdvertiseOptions, NodeEvent, NodeOptions, SubscribeArgs};
+use crate::node::{
+    AdvertiseOptions, GraphEvent, NodeEvent, NodeOptions, StampClock, SubscribeArgs,
+    TopicEndpoint, TransportMetrics,
+};
 use crate::transport::{PublishMessage, ReplyMessage, RequestMessage};
 use crate::utils::topic as topic_utils;
+use rgz_msgs as msgs;
 use rgz_msgs::GzMessage;
 
 pub struct Node {
@@ -27,18 +35,22 @@ pub struct Node {
 
     // Custom options for this node.
     node_options: NodeOptions,
+
+    // Time source of the timers.
+    clock: Clock,
 }
 
 impl Node {
     pub fn new(options: Option<NodeOptions>) -> Self {
         let node_options = options.unwrap_or_default();
         let n_uuid = uuid::Uuid::new_v4().to_string();
-        let node_shared = NodeShared::instance();
+        let node_shared = NodeShared::instance(node_options.domain());
 
         Node {
             n_uuid,
             node_shared,
             node_options,
+            clock: Clock::Wall,
         }
     }
 
@@ -61,11 +73,26 @@ impl Node {
         options: Option<AdvertiseOptions>,
     ) -> Result<Publisher<T>>
         where
-            T: GzMessage,
+            T: GzMessage + Default,
     {
         let advertise_options = options.unwrap_or_default();
         let fully_qualified_topic = self.create_fully_qualified_topic(topic)?;
 
+        if advertise_options.stamps_header() && T::default().header_field_mut().is_none() {
+            bail!("Message type [{}] has no header to stamp", T::TYPE_NAME);
+        }
+        let stamp_clock = match advertise_options.stamp_clock() {
+            Some(StampClock::Wall) => Some(Clock::Wall),
+            Some(StampClock::Sim) => match &self.clock {
+                Clock::Sim(_) => Some(self.clock.clone()),
+                Clock::Wall => bail!(
+                    "Sim time stamps on [{}] require use_sim_time() before advertise()",
+                    topic
+                ),
+            },
+            None => None,
+        };
+
         // Create the discovery publisher
         let message_publisher = DiscoveryMsgPublisher {
             ctrl: "unused".to_string(),
@@ -84,15 +111,27 @@ impl Node {
             pub_type: Some(pub_type),
         };
 
-        let event_sender = {
+        let (event_sender, discovery_store, metrics, compression) = {
             let mut node_shared = self.node_shared.lock().unwrap();
-            node_shared.advertise(discovery_publisher)?
+            let compression = node_shared
+                .register_compression(&fully_qualified_topic, advertise_options.compression())?;
+            let event_sender = node_shared.advertise(discovery_publisher)?;
+            (
+                event_sender,
+                node_shared.msg_discovery_store()?,
+                node_shared.metrics()?,
+                compression,
+            )
         };
 
         Ok(Publisher::<T>::new(
             &fully_qualified_topic,
             advertise_options,
+            stamp_clock,
             event_sender,
+            discovery_store,
+            metrics,
+            compression,
         ))
     }
 
@@ -104,7 +143,7 @@ impl Node {
         let fully_qualified_topic = self.create_fully_qualified_topic(topic)?;
         let (msg_sender, mut msg_receiver) =
             mpsc::unbounded_channel::<PublishMessage>();
-        {
+        let metrics = {
             let mut node_shared = self.node_shared.lock().unwrap();
             node_shared.subscribe(SubscribeArgs {
                 n_uuid: self.n_uuid.to_string(),
@@ -112,14 +151,25 @@ impl Node {
                 msg_type: T::TYPE_NAME.to_string(),
                 sender: msg_sender,
             })?;
+            node_shared.metrics()?
         };
 
         tokio::spawn(async move {
             while let Some(mut msgs) = msg_receiver.recv().await {
-                if let Ok(msg) = T::decode(&msgs.data[..]) {
+                let decoded = T::decode(&msgs.data[..]);
+                {
+                    let mut metrics = metrics.lock().unwrap();
+                    let topic_metrics = metrics.topic_mut(&msgs.topic);
+                    topic_metrics.msgs_received += 1;
+                    topic_metrics.bytes_received += msgs.data.len() as u64;
+                    if decoded.is_err() {
+                        topic_metrics.decode_failures += 1;
+                    }
+                }
+                if let Ok(msg) = decoded {
                     cb(msg);
                 } else {
-                    error!("Failed to decode request");
+                    error!("Failed to decode message");
                 }
             }
         });
@@ -134,22 +184,41 @@ impl Node {
             RES: GzMessage + Default,
             F: FnMut(REQ) -> Result<RES> + Send + 'static,
     {
-        let advertise_options = options.unwrap_or_default();
+        self.advertise_service_raw(
+            topic,
+            REQ::TYPE_NAME,
+            RES::TYPE_NAME,
+            move |data| match REQ::decode(data) {
+                Ok(req) => Ok(cb(req)?.encode_to_vec()),
+                Err(err) => {
+                    error!("Failed to decode request");
+                    Err(err.into())
+                }
+            },
+            options,
+        )
+    }
 
-        // Remap the topic
-        let mut topic = topic.to_string();
-        let partition = self.node_options.partition();
-        let ns = self.node_options.namespace();
-        if let Some(remap_topic) = self.node_options.topic_remap(&topic) {
-            topic = remap_topic.clone();
-        }
-        let fully_qualified_topic = topic_utils::fully_qualified_name(partition, ns, &topic)?;
+    /// Advertise a service that handles the serialized request and response.
+    pub fn advertise_service_raw<F>(
+        &self,
+        topic: &str,
+        req_type: &str,
+        res_type: &str,
+        mut cb: F,
+        options: Option<AdvertiseOptions>,
+    ) -> Result<()>
+    where
+        F: FnMut(&[u8]) -> Result<Vec<u8>> + Send + 'static,
+    {
+        let advertise_options = options.unwrap_or_default();
+        let fully_qualified_topic = self.create_fully_qualified_topic(topic)?;
 
         // Create the discovery publisher
         let service_publisher = DiscoverySrvPublisher {
             socket_id: "".to_string(),
-            request_type: REQ::TYPE_NAME.to_string(),
-            response_type: RES::TYPE_NAME.to_string(),
+            request_type: req_type.to_string(),
+            response_type: res_type.to_string(),
         };
         let pub_type = DiscoveryPubType::SrvPub(service_publisher);
 
@@ -170,33 +239,26 @@ impl Node {
         };
 
         tokio::spawn(async move {
-            while let Some(mut msgs) = request_receiver.recv().await {
-                if let Ok(msg) = REQ::decode(&msgs.data[..]) {
-                    let mut data = vec![];
-                    let mut result = false;
-
-                    if let Ok(res) = cb(msg) {
-                        data = res.encode_to_vec();
-                        result = true;
-                    } else {
+            while let Some(msgs) = request_receiver.recv().await {
+                let (data, result) = match cb(&msgs.data) {
+                    Ok(data) => (data, true),
+                    Err(_) => {
                         error!("Failed to call service");
+                        (vec![], false)
                     }
-
-                    let address = msgs.requester_address;
-                    if let Err(e) = event_sender.send(NodeEvent::Reply(ReplyMessage {
-                        requester_address: Some(address),
-                        requester_id: msgs.requester_id.to_string(),
-                        topic: msgs.topic.to_string(),
-                        node_uuid: msgs.node_uuid.to_string(),
-                        req_uuid: msgs.req_uuid.to_string(),
-                        data,
-                        result,
-                    }))
-                    {
-                        error!("Failed to send reply: {}", e);
-                    }
-                } else {
-                    error!("Failed to decode request");
+                };
+
+                let address = msgs.requester_address;
+                if let Err(e) = event_sender.send(NodeEvent::Reply(ReplyMessage {
+                    requester_address: Some(address),
+                    requester_id: msgs.requester_id.to_string(),
+                    topic: msgs.topic.to_string(),
+                    node_uuid: msgs.node_uuid.to_string(),
+                    req_uuid: msgs.req_uuid.to_string(),
+                    data,
+                    result,
+                })) {
+                    error!("Failed to send reply: {}", e);
                 }
             }
         });
@@ -213,25 +275,53 @@ impl Node {
             REQ: GzMessage + Default,
             RES: GzMessage + Default,
     {
-        let timeout = timeout.unwrap_or(Duration::from_millis(1000));
-
-        // Remap the topic
-        let mut topic = topic.to_string();
-        let partition = self.node_options.partition();
-        let ns = self.node_options.namespace();
-        if let Some(remap_topic) = self.node_options.topic_remap(&topic) {
-            topic = remap_topic.clone();
-        }
-        let fully_qualified_topic = topic_utils::fully_qualified_name(partition, ns, &topic)?;
-
-        let mut data = match request {
+        let data = match request {
             Some(req) => req.encode_to_vec(),
             None => vec![],
         };
+        let (data, result) = self
+            .request_raw(
+                topic,
+                Some(REQ::TYPE_NAME),
+                Some(RES::TYPE_NAME),
+                data,
+                timeout,
+            )
+            .await?;
+        if result {
+            let res = RES::decode(&data[..])?;
+            Ok(Some(res))
+        } else {
+            Ok(None)
+        }
+    }
 
-        let response_receiver = {
+    /// Request a service with a serialized request.
+    ///
+    /// The type names missing are taken from the providers of the service.
+    /// It returns the serialized response and the result of the service.
+    pub async fn request_raw(
+        &self,
+        topic: &str,
+        req_type: Option<&str>,
+        res_type: Option<&str>,
+        data: Vec<u8>,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<u8>, bool)> {
+        let timeout = timeout.unwrap_or(Duration::from_millis(1000));
+        let fully_qualified_topic = self.create_fully_qualified_topic(topic)?;
+        let req_type = req_type.unwrap_or_default();
+        let res_type = res_type.unwrap_or_default();
+
+        let span = info_span!(
+            "request",
+            topic = %fully_qualified_topic,
+            req_type,
+            res_type,
+        );
+        let (response_receiver, metrics) = {
             let mut node_shared = self.node_shared.lock().unwrap();
-            node_shared.request(RequestMessage {
+            let response_receiver = node_shared.request(RequestMessage {
                 replier_address: None,
                 replier_id: "unset".to_string(),
                 topic: fully_qualified_topic.to_string(),
@@ -240,34 +330,112 @@ impl Node {
                 node_uuid: self.n_uuid.to_string(),
                 req_uuid: uuid::Uuid::new_v4().to_string(),
                 data,
-                req_type: REQ::TYPE_NAME.to_string(),
-                res_type: RES::TYPE_NAME.to_string(),
-            })?
+                req_type: req_type.to_string(),
+                res_type: res_type.to_string(),
+            })?;
+            (response_receiver, node_shared.metrics()?)
         };
+        {
+            let mut metrics = metrics.lock().unwrap();
+            let service_metrics = metrics.service_mut(&fully_qualified_topic);
+            service_metrics.requests += 1;
+            service_metrics.pending_requests += 1;
+        }
 
-        let deadline = Instant::now() + timeout;
-        match timeout_at(deadline, response_receiver).await {
-            Ok(Ok(msg)) => {
-                if msg.result {
-                    let res = RES::decode(&msg.data[..])?;
-                    Ok(Some(res))
-                } else {
-                    Ok(None)
-                }
+        let start = Instant::now();
+        let deadline = start + timeout;
+        let response = timeout_at(deadline, response_receiver).instrument(span).await;
+        {
+            let mut metrics = metrics.lock().unwrap();
+            let service_metrics = metrics.service_mut(&fully_qualified_topic);
+            service_metrics.pending_requests -= 1;
+            match response {
+                Ok(Ok(_)) => service_metrics.latency.record(start.elapsed()),
+                _ => service_metrics.failures += 1,
             }
+        }
+        match response {
+            Ok(Ok(msg)) => Ok((msg.data, msg.result)),
             Err(_) => bail!("Did not receive value within {} ms", timeout.as_millis()),
             _ => bail!("Failed to receive value"),
         }
     }
+
+    /// Follow the simulation time published on a clock topic, e.g. `/clock`.
+    ///
+    /// `now()`, `sleep_until()` and the timers created afterwards use it
+    /// instead of the wall time.
+    pub fn use_sim_time(&mut self, topic: &str) -> Result<()> {
+        let (sender, receiver) = watch::channel(Duration::ZERO);
+        self.subscribe(topic, move |msg: msgs::Clock| {
+            if let Some(sim_time) = clock::sim_time(&msg) {
+                sender.send_if_modified(|time| {
+                    let modified = *time != sim_time;
+                    *time = sim_time;
+                    modified
+                });
+            }
+        })?;
+        self.clock = Clock::Sim(receiver);
+        Ok(())
+    }
+
+    /// Current time: the simulation time if `use_sim_time()` was called,
+    /// otherwise the time since the UNIX epoch.
+    pub fn now(&self) -> Duration {
+        self.clock.now()
+    }
+
+    /// Wait until `now()` reaches the deadline.
+    pub async fn sleep_until(&self, deadline: Duration) {
+        self.clock.clone().sleep_until(deadline).await
+    }
+
+    /// Call `cb` every `period` of `now()`. The timer stops when dropped.
+    pub fn create_timer<F>(&self, period: Duration, cb: F) -> Timer
+        where
+            F: FnMut() + Send + 'static,
+    {
+        Timer::start(self.clock.clone(), period, cb)
+    }
+
+    /// Get the subscribers known for a topic, both in this process and in
+    /// remote processes.
+    pub fn subscribers(&self, topic: &str) -> Result<Vec<TopicEndpoint>> {
+        let fully_qualified_topic = self.create_fully_qualified_topic(topic)?;
+        let node_shared = self.node_shared.lock().unwrap();
+        let subscribers = node_shared.subscribers(&fully_qualified_topic)?;
+        Ok(subscribers.iter().map(TopicEndpoint::from).collect())
+    }
+
+    /// Get a snapshot of the transport counters of this process.
+    pub fn metrics(&self) -> Result<TransportMetrics> {
+        let node_shared = self.node_shared.lock().unwrap();
+        let metrics = node_shared.metrics()?;
+        let snapshot = metrics.lock().unwrap().clone();
+        Ok(snapshot)
+    }
+
+    /// Get a stream of changes in the transport graph (publishers, subscribers,
+    /// services and processes) as they are seen by discovery.
+    pub fn graph_events(&self) -> Result<UnboundedReceiver<GraphEvent>> {
+        let mut node_shared = self.node_shared.lock().unwrap();
+        node_shared.graph_events()
+    }
 }
 
 #[derive(Clone)]
 pub struct Publisher<T> {
     topic: String,
     options: AdvertiseOptions,
+    stamp_clock: Option<Clock>,
     sender: UnboundedSender<NodeEvent>,
+    discovery_store: Arc<Mutex<DiscoveryStore>>,
+    metrics: Arc<Mutex<TransportMetrics>>,
     last_sent_msg: Option<Instant>,
     is_ready: Arc<AtomicBool>,
+    // Keeps the codec of the topic while a publisher uses it.
+    _compression: Arc<CompressionRef>,
     _phantom: PhantomData<T>,
 }
 
@@ -275,7 +443,15 @@ impl<T> Publisher<T>
     where
         T: GzMessage,
 {
-    fn new(topic: &str, options: AdvertiseOptions, sender: UnboundedSender<NodeEvent>) -> Self {
+    fn new(
+        topic: &str,
+        options: AdvertiseOptions,
+        stamp_clock: Option<Clock>,
+        sender: UnboundedSender<NodeEvent>,
+        discovery_store: Arc<Mutex<DiscoveryStore>>,
+        metrics: Arc<Mutex<TransportMetrics>>,
+        compression: Arc<CompressionRef>,
+    ) -> Self {
         let is_ready = Arc::new(AtomicBool::new(false));
         let is_ready_clone = is_ready.clone();
         tokio::spawn(async move {
@@ -287,9 +463,13 @@ impl<T> Publisher<T>
         Publisher {
             topic: topic.to_string(),
             options,
+            stamp_clock,
             sender,
+            discovery_store,
+            metrics,
             last_sent_msg: None,
             is_ready,
+            _compression: compression,
             _phantom: PhantomData,
         }
     }
@@ -298,20 +478,94 @@ impl<T> Publisher<T>
         self.is_ready.load(Ordering::Relaxed)
     }
 
-    pub fn publish(&self, msg: T) -> Result<()> {
+    /// Return true if there is any subscriber for this topic and type.
+    ///
+    /// Callers may check it to skip building messages nobody listens to. A
+    /// subscriber of this process only appears once the transport handled its
+    /// subscription, so `publish()` doesn't check it.
+    pub fn has_connections(&self) -> bool {
+        let store = self.discovery_store.lock().unwrap();
+        store.has_subscribers(&self.topic, Some(T::TYPE_NAME))
+    }
+
+    // Write the header as set up by the advertise options.
+    fn stamp(&self, msg: &mut T) {
+        if !self.options.stamps_header() {
+            return;
+        }
+        if let Some(header) = msg.header_field_mut() {
+            if let Some(clock) = &self.stamp_clock {
+                header.set_stamp(clock.now());
+            }
+            if let Some(frame_id) = self.options.frame_id() {
+                header.set_frame_id(frame_id);
+            }
+        }
+    }
+
+    pub fn publish(&self, mut msg: T) -> Result<()> {
         // TODO: Implement throttling
 
         if !self.is_ready.load(Ordering::Relaxed) {
             bail!("Publisher not ready");
         }
 
+        let _span = info_span!("publish", topic = %self.topic, msg_type = T::TYPE_NAME).entered();
+        self.stamp(&mut msg);
+        let data = msg.encode_to_vec();
+        let len = data.len() as u64;
         self.sender.send(NodeEvent::Publish(PublishMessage {
             topic: self.topic.clone(),
             publisher_address: "unset".to_string(),
             msg_type: T::TYPE_NAME.to_string(),
-            data: msg.encode_to_vec(),
+            data,
         }))?;
 
+        let mut metrics = self.metrics.lock().unwrap();
+        let topic_metrics = metrics.topic_mut(&self.topic);
+        topic_metrics.msgs_published += 1;
+        topic_metrics.bytes_published += len;
+        Ok(())
+    }
+
+    /// Publish several messages at once, in order.
+    ///
+    /// The messages go through a single event, which saves the per message
+    /// overhead of `publish()` for high rate producers.
+    pub fn publish_batch<I>(&self, msgs: I) -> Result<()>
+        where
+            I: IntoIterator<Item = T>,
+    {
+        if !self.is_ready.load(Ordering::Relaxed) {
+            bail!("Publisher not ready");
+        }
+
+        let _span = info_span!("publish", topic = %self.topic, msg_type = T::TYPE_NAME).entered();
+        let mut len = 0;
+        let batch: Vec<PublishMessage> = msgs
+            .into_iter()
+            .map(|mut msg| {
+                self.stamp(&mut msg);
+                let data = msg.encode_to_vec();
+                len += data.len() as u64;
+                PublishMessage {
+                    topic: self.topic.clone(),
+                    publisher_address: "unset".to_string(),
+                    msg_type: T::TYPE_NAME.to_string(),
+                    data,
+                }
+            })
+            .collect();
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let count = batch.len() as u64;
+        self.sender.send(NodeEvent::PublishBatch(batch))?;
+
+        let mut metrics = self.metrics.lock().unwrap();
+        let topic_metrics = metrics.topic_mut(&self.topic);
+        topic_metrics.msgs_published += count;
+        topic_metrics.bytes_published += len;
         Ok(())
     }
 }
@@ -319,124 +573,393 @@ impl<T> Publisher<T>
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
+    use prost::Message;
     use tokio::time::sleep;
     use rgz_msgs::StringMsg;
 
     use futures::stream::StreamExt;
     use futures::channel::mpsc::channel as futures_channel;
 
+    use once_cell::sync::Lazy;
+    use tokio::runtime::{Builder, Runtime};
+
     use super::*;
+    use crate::Compression;
 
-    #[tokio::test]
-    async fn test_pub_sub() {
-        let topic = "/foo";
-        let mut node = Node::new(None);
-
-        let recv_msg = Arc::new(Mutex::new(None));
-        let m = recv_msg.clone();
-        node.subscribe(topic, move |msg: StringMsg| {
-            *m.lock().unwrap() = Some(msg);
-        }).unwrap();
-
-        let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
-        while !publisher.is_ready() {
-            println!("Waiting for publisher to be ready...");
-            sleep(Duration::from_millis(200)).await;
-        }
+    // The transport of the default domain is created by the first test and
+    // runs on its runtime, so every test runs on this one.
+    static RUNTIME: Lazy<Runtime> =
+        Lazy::new(|| Builder::new_multi_thread().enable_all().build().unwrap());
 
-        let str_msg = StringMsg {
-            data: "hello world".to_string(),
-            ..Default::default()
-        };
-        publisher.publish(str_msg).unwrap();
+    fn run<F: Future>(test: F) -> F::Output {
+        RUNTIME.block_on(test)
+    }
 
-        sleep(Duration::from_millis(100)).await;
+    #[test]
+    fn test_pub_sub() {
+        run(async {
+            let topic = "/foo";
+            let mut node = Node::new(None);
+
+            let recv_msg = Arc::new(Mutex::new(None));
+            let m = recv_msg.clone();
+            node.subscribe(topic, move |msg: StringMsg| {
+                *m.lock().unwrap() = Some(msg);
+            }).unwrap();
+
+            let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
+            while !publisher.is_ready() {
+                println!("Waiting for publisher to be ready...");
+                sleep(Duration::from_millis(200)).await;
+            }
 
-        {
-            let msg = recv_msg.lock().unwrap();
-            assert_eq!(msg.is_some(), true);
-            let string_msg = msg.as_ref().unwrap();
-            assert_eq!(string_msg.data, "hello world".to_string());
-        }
+            let str_msg = StringMsg {
+                data: "hello world".to_string(),
+                ..Default::default()
+            };
+            publisher.publish(str_msg).unwrap();
+
+            sleep(Duration::from_millis(100)).await;
+
+            {
+                let msg = recv_msg.lock().unwrap();
+                assert_eq!(msg.is_some(), true);
+                let string_msg = msg.as_ref().unwrap();
+                assert_eq!(string_msg.data, "hello world".to_string());
+            }
+        });
     }
 
-    #[tokio::test]
-    async fn test_pub_sub_stream() {
-        let topic = "/foo";
-        let mut node = Node::new(None);
+    #[test]
+    fn test_publish_batch() {
+        run(async {
+            let topic = "/batch";
+            let mut node = Node::new(None);
+
+            let recv_msgs = Arc::new(Mutex::new(vec![]));
+            let m = recv_msgs.clone();
+            node.subscribe(topic, move |msg: StringMsg| {
+                m.lock().unwrap().push(msg.data);
+            }).unwrap();
+
+            let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
+            while !publisher.is_ready() {
+                sleep(Duration::from_millis(50)).await;
+            }
+            let msgs = (0..3).map(|i| StringMsg {
+                data: i.to_string(),
+                ..Default::default()
+            });
+            publisher.publish_batch(msgs).unwrap();
 
-        let (mut sender, mut receiver) = futures_channel::<StringMsg>(10);
+            sleep(Duration::from_millis(100)).await;
+            assert_eq!(*recv_msgs.lock().unwrap(), vec!["0", "1", "2"]);
+        });
+    }
 
-        node.subscribe(topic, move |msg: StringMsg| {
-            if let Err(e) = sender.try_send(msg) {
-                eprintln!("error: {}", e);
+    #[test]
+    fn test_use_sim_time() {
+        run(async {
+            let topic = "/sim_clock";
+            let mut node = Node::new(None);
+            node.use_sim_time(topic).unwrap();
+            assert_eq!(node.now(), Duration::ZERO);
+
+            let publisher = node.advertise::<msgs::Clock>(topic, None).unwrap();
+            while !publisher.is_ready() {
+                sleep(Duration::from_millis(50)).await;
             }
-        }).unwrap();
+            publisher.publish(msgs::Clock {
+                sim: Some(msgs::Time { sec: 12, nsec: 0 }),
+                ..Default::default()
+            }).unwrap();
 
-        let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
-        while !publisher.is_ready() {
-            println!("Waiting for publisher to be ready...");
-            sleep(Duration::from_millis(200)).await;
-        }
+            tokio::time::timeout(Duration::from_secs(1), node.sleep_until(Duration::from_secs(12)))
+                .await
+                .unwrap();
+            assert_eq!(node.now(), Duration::from_secs(12));
+        });
+    }
+
+    #[test]
+    fn test_stamp_header() {
+        run(async {
+            let topic = "/stamped";
+            let mut node = Node::new(None);
+
+            let recv_msg = Arc::new(Mutex::new(None));
+            let m = recv_msg.clone();
+            node.subscribe(topic, move |msg: StringMsg| {
+                *m.lock().unwrap() = Some(msg);
+            }).unwrap();
+
+            let mut options = AdvertiseOptions::new();
+            options.set_stamp_clock(StampClock::Wall);
+            options.set_frame_id("base_link");
+            assert!(node.advertise::<msgs::Empty>(topic, Some(options.clone())).is_err());
+
+            let mut sim_options = AdvertiseOptions::new();
+            sim_options.set_stamp_clock(StampClock::Sim);
+            assert!(node.advertise::<StringMsg>(topic, Some(sim_options)).is_err());
+
+            let publisher = node.advertise::<StringMsg>(topic, Some(options)).unwrap();
+            while !publisher.is_ready() {
+                sleep(Duration::from_millis(50)).await;
+            }
+            let before = node.now();
+            publisher.publish(StringMsg::default()).unwrap();
+            sleep(Duration::from_millis(100)).await;
+
+            let msg = recv_msg.lock().unwrap().take().unwrap();
+            let header = msg.header.unwrap();
+            assert_eq!(header.frame_id(), Some("base_link"));
+            let stamp = header.stamp.unwrap();
+            assert!(Duration::new(stamp.sec as u64, stamp.nsec as u32) >= before);
+        });
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message, GzMessage)]
+    #[gz(type_name = "rgz.test.Person")]
+    struct Person {
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "2")]
+        #[gz(header)]
+        pub header: ::core::option::Option<msgs::Header>,
+    }
+
+    #[test]
+    fn test_custom_message() {
+        run(async {
+            assert_eq!(Person::TYPE_NAME, "rgz.test.Person");
+            let topic = "/person";
+            let mut node = Node::new(None);
+
+            let recv_msg = Arc::new(Mutex::new(None));
+            let m = recv_msg.clone();
+            node.subscribe(topic, move |msg: Person| {
+                *m.lock().unwrap() = Some(msg);
+            }).unwrap();
+
+            let mut options = AdvertiseOptions::new();
+            options.set_frame_id("home");
+            let publisher = node.advertise::<Person>(topic, Some(options)).unwrap();
+            while !publisher.is_ready() {
+                sleep(Duration::from_millis(50)).await;
+            }
+            publisher.publish(Person {
+                name: "Alice".to_string(),
+                ..Default::default()
+            }).unwrap();
+            sleep(Duration::from_millis(100)).await;
+
+            let msg = recv_msg.lock().unwrap().take().unwrap();
+            assert_eq!(msg.name, "Alice");
+            assert_eq!(msg.header.unwrap().frame_id(), Some("home"));
+        });
+    }
+
+    #[test]
+    fn test_advertise_compression() {
+        run(async {
+            let topic = "/compressed";
+            let node1 = Node::new(None);
+            let node2 = Node::new(None);
+
+            let mut options = AdvertiseOptions::new();
+            options.set_compression(Compression::Lz4);
+            let publisher1 = node1.advertise::<StringMsg>(topic, Some(options.clone())).unwrap();
+            // The publishers of a topic in a process share its codec.
+            assert!(node2.advertise::<StringMsg>(topic, None).is_err());
+            let publisher2 = node2.advertise::<StringMsg>(topic, Some(options)).unwrap();
+
+            // The codec is released with the last publisher of the topic.
+            drop(publisher1);
+            assert!(node2.advertise::<StringMsg>(topic, None).is_err());
+            drop(publisher2);
+            node2.advertise::<StringMsg>(topic, None).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_has_connections() {
+        run(async {
+            let topic = "/bar";
+            let mut node = Node::new(None);
+
+            let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
+            sleep(Duration::from_millis(50)).await;
+            assert!(!publisher.has_connections());
+            assert_eq!(node.subscribers(topic).unwrap().len(), 0);
+
+            node.subscribe(topic, move |_msg: StringMsg| {}).unwrap();
+            sleep(Duration::from_millis(50)).await;
+            assert!(publisher.has_connections());
+
+            let subscribers = node.subscribers(topic).unwrap();
+            assert_eq!(subscribers.len(), 1);
+            assert_eq!(subscribers[0].msg_type, StringMsg::TYPE_NAME);
+        });
+    }
+
+    #[test]
+    fn test_pub_sub_stream() {
+        run(async {
+            let topic = "/foo_stream";
+            let mut node = Node::new(None);
+
+            let (mut sender, mut receiver) = futures_channel::<StringMsg>(10);
+
+            node.subscribe(topic, move |msg: StringMsg| {
+                if let Err(e) = sender.try_send(msg) {
+                    eprintln!("error: {}", e);
+                }
+            }).unwrap();
+
+            let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
+            while !publisher.is_ready() {
+                println!("Waiting for publisher to be ready...");
+                sleep(Duration::from_millis(200)).await;
+            }
+
+            for i in 0..10 {
+                let str_msg = StringMsg {
+                    data: format!("hello world: {}", i),
+                    ..Default::default()
+                };
+                publisher.publish(str_msg).unwrap();
+                let msg = receiver.next().await.unwrap();
+                assert_eq!(msg.data, format!("hello world: {}", i));
+            }
+        });
+    }
+
+    #[test]
+    fn test_req_res() {
+        run(async {
+            let topic = "/echo";
+            let node = Node::new(None);
+            node.advertise_service(topic, move |req: StringMsg| {
+                Ok(req)
+            }, None).unwrap();
 
-        for i in 0..10 {
             let str_msg = StringMsg {
-                data: format!("hello world: {}", i),
+                data: "HELLO".to_string(),
                 ..Default::default()
             };
-            publisher.publish(str_msg).unwrap();
-            let msg = receiver.next().await.unwrap();
-            assert_eq!(msg.data, format!("hello world: {}", i));
-        }
+            let request = Some(str_msg);
+            let timeout = Some(Duration::from_secs(1));
+            let res = node
+                .request::<StringMsg, StringMsg>(topic, request, timeout)
+                .await.unwrap();
+
+            assert_eq!(res.is_some(), true);
+            let string_msg = res.unwrap();
+            assert_eq!(string_msg.data, "HELLO".to_string());
+        });
     }
 
-    #[tokio::test]
-    async fn test_req_res() {
-        let topic = "/echo";
-        let node = Node::new(None);
-        node.advertise_service(topic, move |req: StringMsg| {
-            Ok(req)
-        }, None).unwrap();
+    #[test]
+    fn test_req_res_raw() {
+        run(async {
+            let topic = "/raw_echo";
+            let node = Node::new(None);
+            node.advertise_service_raw(topic, StringMsg::TYPE_NAME, StringMsg::TYPE_NAME, move |req| {
+                Ok(req.to_vec())
+            }, None).unwrap();
+
+            let data = StringMsg {
+                data: "HELLO".to_string(),
+                ..Default::default()
+            }
+            .encode_to_vec();
+            let timeout = Some(Duration::from_secs(1));
+            // The type names are taken from the service.
+            let (res, result) = node
+                .request_raw(topic, None, None, data.clone(), timeout)
+                .await
+                .unwrap();
+            assert!(result);
+            assert_eq!(res, data);
+
+            let res = node
+                .request_raw(topic, Some("gz.msgs.Empty"), None, data, timeout)
+                .await;
+            assert!(res.is_err());
+        });
+    }
 
-        let str_msg = StringMsg {
-            data: "HELLO".to_string(),
-            ..Default::default()
-        };
-        let request = Some(str_msg);
-        let timeout = Some(Duration::from_secs(1));
-        let res = node
-            .request::<StringMsg, StringMsg>(topic, request, timeout)
-            .await.unwrap();
-
-        assert_eq!(res.is_some(), true);
-        let string_msg = res.unwrap();
-        assert_eq!(string_msg.data, "HELLO".to_string());
-    }
-
-    #[tokio::test]
-    async fn test_req_res_error() {
-        tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::INFO)
-            .init();
-
-        let topic = "/echo";
-        let node = Node::new(None);
-        node.advertise_service(topic, move |req: StringMsg| {
-            bail!("error");
-            Ok(req)
-        }, None).unwrap();
-
-        let str_msg = StringMsg {
-            data: "HELLO".to_string(),
-            ..Default::default()
-        };
-        let request = Some(str_msg);
-        let timeout = Some(Duration::from_secs(1));
-        let res = node
-            .request::<StringMsg, StringMsg>(topic, request, timeout)
-            .await.unwrap();
+    #[test]
+    fn test_metrics() {
+        run(async {
+            let topic = "/metrics";
+            let mut node = Node::new(None);
+            node.subscribe(topic, move |_msg: StringMsg| {}).unwrap();
+            node.advertise_service("/metrics_echo", move |req: StringMsg| {
+                Ok(req)
+            }, None).unwrap();
+
+            let publisher = node.advertise::<StringMsg>(topic, None).unwrap();
+            while !publisher.is_ready() {
+                sleep(Duration::from_millis(50)).await;
+            }
+            let str_msg = StringMsg {
+                data: "hello".to_string(),
+                ..Default::default()
+            };
+            let len = str_msg.encoded_len() as u64;
+            publisher.publish(str_msg.clone()).unwrap();
+            publisher.publish(str_msg.clone()).unwrap();
+            node.request::<StringMsg, StringMsg>("/metrics_echo", Some(str_msg), None)
+                .await.unwrap();
+            sleep(Duration::from_millis(100)).await;
+
+            let metrics = node.metrics().unwrap();
+            let fully_qualified_topic = node.create_fully_qualified_topic(topic).unwrap();
+            let topic_metrics = metrics.topic(&fully_qualified_topic).unwrap();
+            assert_eq!(topic_metrics.msgs_published, 2);
+            assert_eq!(topic_metrics.bytes_published, 2 * len);
+            assert_eq!(topic_metrics.msgs_received, 2);
+            assert_eq!(topic_metrics.bytes_received, 2 * len);
+            assert_eq!(topic_metrics.decode_failures, 0);
+            assert_eq!(topic_metrics.dropped, 0);
+            assert_eq!(topic_metrics.send_errors, 0);
+
+            let service = node.create_fully_qualified_topic("/metrics_echo").unwrap();
+            let service_metrics = metrics.service(&service).unwrap();
+            assert_eq!(service_metrics.requests, 1);
+            assert_eq!(service_metrics.pending_requests, 0);
+            assert_eq!(service_metrics.failures, 0);
+            assert_eq!(service_metrics.latency.count, 1);
+        });
+    }
+
+    #[test]
+    fn test_req_res_error() {
+        run(async {
+            let _ = tracing_subscriber::fmt()
+                .with_max_level(tracing::Level::INFO)
+                .try_init();
+
+            let topic = "/echo_error";
+            let node = Node::new(None);
+            node.advertise_service(topic, move |req: StringMsg| {
+                bail!("error");
+                Ok(req)
+            }, None).unwrap();
 
-        assert_eq!(res.is_some(), false);
+            let str_msg = StringMsg {
+                data: "HELLO".to_string(),
+                ..Default::default()
+            };
+            let request = Some(str_msg);
+            let timeout = Some(Duration::from_secs(1));
+            let res = node
+                .request::<StringMsg, StringMsg>(topic, request, timeout)
+                .await.unwrap();
+
+            assert_eq!(res.is_some(), false);
+        });
     }
 
 }
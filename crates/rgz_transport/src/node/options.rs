@@ -1,22 +1,57 @@
 use anyhow::{bail, Result};
 use std::collections::HashMap;
 
+use crate::compression::Compression;
 use crate::discovery::DiscoveryScope;
 use crate::utils::net as net_utils;
 use crate::utils::topic as topic_utils;
 
 const UNTHROTTLED: u64 = u64::MAX;
 
+/// How a request picks among the remote processes providing a service.
+///
+/// Services of this process are always used first. If a provider can not be
+/// reached, the request fails over to the next one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ServiceSelection {
+    /// The first provider discovered.
+    #[default]
+    First,
+    RoundRobin,
+    Random,
+    /// Providers on this machine first: on a loopback address, `localhost`
+    /// or an address of this host.
+    PreferLocal,
+    /// Providers on the same host IP as this process first.
+    PreferSameHost,
+}
+
+/// Clock of the header stamps set by a publisher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StampClock {
+    /// Time since the UNIX epoch.
+    #[default]
+    Wall,
+    /// Simulation time of the node, see `Node::use_sim_time()`.
+    Sim,
+}
+
 #[derive(Debug, Clone)]
 pub struct AdvertiseOptions {
     scope: DiscoveryScope,
     msgs_per_sec: u64,
+    compression: Compression,
+    stamp_clock: Option<StampClock>,
+    frame_id: Option<String>,
 }
 impl AdvertiseOptions {
     pub fn new() -> Self {
         Self {
             scope: DiscoveryScope::All,
             msgs_per_sec: UNTHROTTLED,
+            compression: Compression::None,
+            stamp_clock: None,
+            frame_id: None,
         }
     }
 
@@ -38,6 +73,37 @@ impl AdvertiseOptions {
     pub fn throttled(&self) -> bool {
         self.msgs_per_sec != UNTHROTTLED
     }
+
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+    /// Compress the messages sent to remote subscribers.
+    ///
+    /// Subscribers built with gz-transport can not decompress them.
+    pub fn set_compression(&mut self, compression: Compression) {
+        self.compression = compression;
+    }
+
+    pub fn stamp_clock(&self) -> Option<StampClock> {
+        self.stamp_clock
+    }
+    /// Set the header stamp of the published messages to the time of the clock.
+    pub fn set_stamp_clock(&mut self, clock: StampClock) {
+        self.stamp_clock = Some(clock);
+    }
+
+    pub fn frame_id(&self) -> Option<&str> {
+        self.frame_id.as_deref()
+    }
+    /// Set the `frame_id` header data of the published messages.
+    pub fn set_frame_id(&mut self, frame_id: &str) {
+        self.frame_id = Some(frame_id.to_string());
+    }
+
+    /// Whether the publisher writes the message headers.
+    pub(crate) fn stamps_header(&self) -> bool {
+        self.stamp_clock.is_some() || self.frame_id.is_some()
+    }
 }
 
 impl Default for AdvertiseOptions {
@@ -50,6 +116,7 @@ pub struct NodeOptions {
     ns: String,
     partition: String,
     topics_remap: HashMap<String, String>,
+    domain: String,
 }
 impl NodeOptions {
     pub fn new() -> Self {
@@ -57,6 +124,7 @@ impl NodeOptions {
             ns: "".to_string(),
             partition: format!("{}:{}", net_utils::hostname(), net_utils::username()),
             topics_remap: HashMap::new(),
+            domain: "".to_string(),
         }
     }
 
@@ -103,6 +171,14 @@ impl NodeOptions {
         // Is there any remap for this topic?
         self.topics_remap.get(from_topic)
     }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+    /// Use the transport of a domain, see `TransportContext::set_domain()`.
+    pub fn set_domain(&mut self, domain: &str) {
+        self.domain = domain.to_string();
+    }
 }
 
 impl Default for NodeOptions {
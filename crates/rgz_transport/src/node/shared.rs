@@ -1,11 +1,14 @@
 // use std::borrow::BorrowMut;
 use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
-use std::{env, process};
+use std::env;
 
 use anyhow::{bail, Result};
 use once_cell::sync::Lazy;
 use prost::bytes::Buf;
+use rand::Rng;
 use tokio::select;
 
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
@@ -13,8 +16,10 @@ use tokio::sync::oneshot;
 
 use tracing::{debug, error, info, trace};
 
+use crate::compression::{Compression, CompressionRef, TopicCompressions};
+use crate::context::TransportContext;
 use crate::discovery::{
-    Discovery, DiscoveryPubType, DiscoveryPublisher,
+    Discovery, DiscoveryBackendType, DiscoveryMsgPublisher, DiscoveryPubType, DiscoveryPublisher,
     DiscoveryStore,
 };
 use crate::dispatcher::{
@@ -22,11 +27,10 @@ use crate::dispatcher::{
     ServiceDispatcher, Subscriber,
 };
 use crate::node::{
-    NodeEvent, SubscribeArgs, TransportEvent, DEFAULT_DISCOVERY_IP, DEFAULT_MSG_DISC_PORT,
-    DEFAULT_SRV_DISC_PORT,
+    GraphEvent, NodeEvent, ServiceSelection, SubscribeArgs, TransportEvent, TransportMetrics,
 };
+use crate::security::Security;
 use crate::transport::{PublishMessage, ReplyMessage, RequestMessage, Transporter};
-use crate::utils::env as env_utils;
 
 #[derive(Debug)]
 enum DiscoveryEvent {
@@ -38,7 +42,31 @@ enum DiscoveryEvent {
     SrvDisconnection(DiscoveryPublisher),
 }
 
-static NODE_SHARED_MAP: Lazy<Mutex<HashMap<u32, Arc<Mutex<NodeShared>>>>> =
+impl DiscoveryEvent {
+    /// Convert the discovery event into the public graph event.
+    fn graph_event(&self) -> GraphEvent {
+        match self {
+            DiscoveryEvent::Connection(p) => GraphEvent::PublisherAppeared(p.into()),
+            DiscoveryEvent::Disconnection(p) | DiscoveryEvent::SrvDisconnection(p)
+                if p.topic.is_empty() =>
+            {
+                // Only the process UUID is set when a whole process is gone.
+                GraphEvent::ProcessSilent(p.process_uuid.to_string())
+            }
+            DiscoveryEvent::Disconnection(p) => GraphEvent::PublisherDisappeared(p.into()),
+            DiscoveryEvent::Registration(p) => GraphEvent::SubscriberRegistered(p.into()),
+            DiscoveryEvent::Unregistration(p) => GraphEvent::SubscriberUnregistered(p.into()),
+            DiscoveryEvent::SrvConnection(p) => GraphEvent::ServiceAdvertised(p.into()),
+            DiscoveryEvent::SrvDisconnection(p) => GraphEvent::ServiceRemoved(p.into()),
+        }
+    }
+}
+
+/// Maximum number of publications handled in one batch.
+const MAX_COALESCED_MSGS: usize = 256;
+
+// The transport instances of this process, by domain.
+static NODE_SHARED_MAP: Lazy<Mutex<HashMap<String, Arc<Mutex<NodeShared>>>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
 pub(crate) struct NodeShared {
@@ -46,88 +74,124 @@ pub(crate) struct NodeShared {
     discovery_ip: String,
     msg_disc_port: u16,
     srv_disc_port: u16,
+    discovery_backend: DiscoveryBackendType,
+    security: Security,
+    ipc: bool,
+    service_selection: ServiceSelection,
+    coalesce_publish: bool,
     node_event_sender: Option<UnboundedSender<NodeEvent>>,
+    msg_discovery_store: Option<Arc<Mutex<DiscoveryStore>>>,
+    metrics: Option<Arc<Mutex<TransportMetrics>>>,
+    // Codec of the topics advertised in this process.
+    compressions: TopicCompressions,
     verbose: bool,
     handle: Option<tokio::task::JoinHandle<()>>,
 }
 impl NodeShared {
-    pub fn instance() -> Arc<Mutex<NodeShared>> {
-        let pid = process::id();
+    /// The instance of a domain, started with the default context if needed.
+    pub fn instance(domain: &str) -> Arc<Mutex<NodeShared>> {
         let mut node_shared_map = NODE_SHARED_MAP.lock().unwrap();
-        return if let Some(node_shared) = node_shared_map.get(&pid) {
-            node_shared.clone()
-        } else {
-            // No instance found, create a new one and insert it into the map.
-            let mut new_node_shared = NodeShared::new();
-            new_node_shared.start();
-            std::thread::sleep(std::time::Duration::from_millis(5));
-            let new_node_shared = Arc::new(Mutex::new(new_node_shared));
-            node_shared_map.insert(pid, new_node_shared.clone());
-            new_node_shared
-        };
-    }
-    fn new() -> Self {
-        let mut verbose = false;
-        let p_uuid = uuid::Uuid::new_v4().to_string();
-        let mut discovery_ip = DEFAULT_DISCOVERY_IP.to_string();
-        // If GZ_VERBOSE=1 enable the verbose mode.
-        if let Ok(gz_verbose) = env::var("GZ_VERBOSE") {
-            if !gz_verbose.is_empty() {
-                verbose = gz_verbose == "1";
-            }
+        if let Some(node_shared) = node_shared_map.get(domain) {
+            return node_shared.clone();
         }
-        // Set the multicast IP used for discovery.
-        if let Ok(ip) = env::var("GZ_DISCOVERY_MULTICAST_IP") {
-            if !ip.is_empty() {
-                discovery_ip = ip;
+        let mut context = TransportContext::new();
+        context.set_domain(domain);
+        match Self::start_instance(&mut node_shared_map, &context) {
+            Ok(node_shared) => node_shared,
+            Err(err) => {
+                // The nodes report "Node is not started", the next one retries.
+                error!("Failed to start the transport: {:#}", err);
+                Arc::new(Mutex::new(NodeShared::with_context(&context)))
             }
         }
-        // Set the port used for msg discovery.
-        let msg_disc_port =
-            env_utils::non_negative_env_var("GZ_DISCOVERY_MSG_PORT", DEFAULT_MSG_DISC_PORT);
-        // Set the port used for srv discovery.
-        let mut srv_disc_port =
-            env_utils::non_negative_env_var("GZ_DISCOVERY_SRV_PORT", DEFAULT_SRV_DISC_PORT);
-
-        // Sanity check: the discovery ports should be unique.
-        if msg_disc_port == srv_disc_port {
-            if msg_disc_port < 65535 {
-                srv_disc_port += 1;
-            } else {
-                srv_disc_port -= 1;
-            }
-            eprintln!(
-                "Your discovery ports are the same [{}]. \
-                Using [{}] for messages and [{}] for services",
-                msg_disc_port, msg_disc_port, srv_disc_port
-            );
+    }
+    pub(crate) fn init(context: TransportContext) -> Result<()> {
+        let mut node_shared_map = NODE_SHARED_MAP.lock().unwrap();
+        if node_shared_map.contains_key(context.domain()) {
+            bail!("The transport is already initialized");
         }
-
+        Self::start_instance(&mut node_shared_map, &context)?;
+        Ok(())
+    }
+    fn start_instance(
+        node_shared_map: &mut HashMap<String, Arc<Mutex<NodeShared>>>,
+        context: &TransportContext,
+    ) -> Result<Arc<Mutex<NodeShared>>> {
+        let mut node_shared = NodeShared::with_context(context);
+        node_shared.start()?;
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let node_shared = Arc::new(Mutex::new(node_shared));
+        node_shared_map.insert(context.domain().to_string(), node_shared.clone());
+        Ok(node_shared)
+    }
+    /// Stop the instances of every domain. The next `instance()` creates a new one.
+    pub(crate) async fn shutdown() -> Result<()> {
+        let node_shareds: Vec<_> = NODE_SHARED_MAP.lock().unwrap().drain().collect();
+        let mut handles = vec![];
+        for (_, node_shared) in node_shareds {
+            handles.extend(node_shared.lock().unwrap().stop()?);
+        }
+        for handle in handles {
+            handle.await?;
+        }
+        Ok(())
+    }
+    fn stop(&mut self) -> Result<Option<tokio::task::JoinHandle<()>>> {
+        if let Some(node_event_sender) = self.node_event_sender.take() {
+            node_event_sender.send(NodeEvent::Shutdown)?;
+        }
+        self.msg_discovery_store = None;
+        self.metrics = None;
+        Ok(self.handle.take())
+    }
+    #[cfg(test)]
+    fn new() -> Self {
+        Self::with_context(&TransportContext::new())
+    }
+    fn with_context(context: &TransportContext) -> Self {
         NodeShared {
-            p_uuid,
-            discovery_ip,
-            msg_disc_port,
-            srv_disc_port,
+            p_uuid: uuid::Uuid::new_v4().to_string(),
+            discovery_ip: context.discovery_ip().to_string(),
+            msg_disc_port: context.msg_disc_port(),
+            srv_disc_port: context.srv_disc_port(),
+            discovery_backend: context.discovery_backend().clone(),
+            security: context.security(),
+            ipc: context.ipc(),
+            service_selection: context.service_selection(),
+            coalesce_publish: context.coalesce_publish(),
             node_event_sender: None,
-            verbose,
+            msg_discovery_store: None,
+            metrics: None,
+            compressions: TopicCompressions::default(),
+            verbose: context.verbose(),
             handle: None,
         }
     }
-    fn start(&mut self) {
+    fn start(&mut self) -> Result<()> {
         let mut inner = NodeSharedInner::new(
             &self.p_uuid,
             &self.discovery_ip,
             self.msg_disc_port,
             self.srv_disc_port,
+            &self.discovery_backend,
+            self.security.clone(),
+            self.ipc,
             self.verbose,
-        );
+        )?;
+        inner.service_selection = self.service_selection;
+        inner.coalesce_publish = self.coalesce_publish;
+        inner.compressions = self.compressions.clone();
         let node_event_sender = inner.node_event_sender();
         self.node_event_sender = Some(node_event_sender);
+        self.msg_discovery_store = Some(inner.msg_discovery.discovery_store());
+        self.metrics = Some(inner.metrics.clone());
+        inner.transporter.start()?;
 
         let handle = tokio::spawn(async move {
             inner.run().await;
         });
         self.handle = Some(handle);
+        Ok(())
     }
     pub(crate) fn advertise(
         &mut self,
@@ -141,6 +205,14 @@ impl NodeShared {
             }
         }
     }
+    /// Register the codec of a topic for the publishers of this process.
+    pub(crate) fn register_compression(
+        &self,
+        topic: &str,
+        compression: Compression,
+    ) -> Result<Arc<CompressionRef>> {
+        self.compressions.register(topic, compression)
+    }
     pub(crate) fn advertise_service(
         &mut self,
         discovery_publisher: DiscoveryPublisher,
@@ -179,13 +251,37 @@ impl NodeShared {
             }
         }
     }
+    pub(crate) fn msg_discovery_store(&self) -> Result<Arc<Mutex<DiscoveryStore>>> {
+        match self.msg_discovery_store.as_ref() {
+            None => bail!("Node is not started"),
+            Some(store) => Ok(store.clone()),
+        }
+    }
+    pub(crate) fn metrics(&self) -> Result<Arc<Mutex<TransportMetrics>>> {
+        match self.metrics.as_ref() {
+            None => bail!("Node is not started"),
+            Some(metrics) => Ok(metrics.clone()),
+        }
+    }
+    pub(crate) fn subscribers(&self, topic: &str) -> Result<Vec<DiscoveryPublisher>> {
+        let store = self.msg_discovery_store()?;
+        let store = store.lock().unwrap();
+        Ok(store.subscribers(topic).into_iter().cloned().collect())
+    }
+    pub(crate) fn graph_events(&mut self) -> Result<UnboundedReceiver<GraphEvent>> {
+        match self.node_event_sender.as_ref() {
+            None => bail!("Node is not started"),
+            Some(node_event_sender) => {
+                let (sender, receiver) = mpsc::unbounded_channel::<GraphEvent>();
+                node_event_sender.send(NodeEvent::GraphEvents(sender))?;
+                Ok(receiver)
+            }
+        }
+    }
 }
 
 impl Drop for NodeShared {
     fn drop(&mut self) {
-        let pid = process::id();
-        let mut node_shared_map = NODE_SHARED_MAP.lock().unwrap();
-        node_shared_map.remove(&pid);
         if let Some(handle) = self.handle.take() {
             handle.abort();
         }
@@ -211,6 +307,18 @@ struct NodeSharedInner {
     response_dispatchers: DispatcherStore<ResponseDispatcher>,
     services: DispatcherStore<ServiceDispatcher>,
 
+    graph_event_senders: Vec<UnboundedSender<GraphEvent>>,
+    metrics: Arc<Mutex<TransportMetrics>>,
+
+    // Prefer IPC for the publishers on this host.
+    ipc: bool,
+    service_selection: ServiceSelection,
+    // Handle the queued publications in batches.
+    coalesce_publish: bool,
+    // Codec of the topics advertised in this process.
+    compressions: TopicCompressions,
+    // Requests sent per service, for the round-robin selection.
+    service_rounds: HashMap<String, usize>,
     verbose: bool,
 }
 
@@ -220,8 +328,11 @@ impl NodeSharedInner {
         discovery_ip: &str,
         msg_disc_port: u16,
         srv_disc_port: u16,
+        discovery_backend: &DiscoveryBackendType,
+        security: Security,
+        ipc: bool,
         verbose: bool,
-    ) -> Self {
+    ) -> Result<Self> {
         let (discovery_event_sender, discovery_event_receiver) =
             mpsc::unbounded_channel::<DiscoveryEvent>();
         let (node_event_sender, node_event_receiver) = mpsc::unbounded_channel::<NodeEvent>();
@@ -230,6 +341,7 @@ impl NodeSharedInner {
 
         // msg discovery
         let mut msg_discovery = Discovery::new(p_uuid, discovery_ip, msg_disc_port, verbose);
+        msg_discovery.set_backend_type(discovery_backend.clone());
 
         let sender = discovery_event_sender.clone();
         msg_discovery.set_connection_cb(move |discovery_publisher| {
@@ -258,6 +370,7 @@ impl NodeSharedInner {
 
         // srv discovery
         let mut srv_discovery = Discovery::new(p_uuid, discovery_ip, srv_disc_port, verbose);
+        srv_discovery.set_backend_type(discovery_backend.clone());
         let sender = discovery_event_sender.clone();
         srv_discovery.set_connection_cb(move |discovery_publisher| {
             sender
@@ -273,21 +386,37 @@ impl NodeSharedInner {
 
         // transporter
         let host_addr = msg_discovery.host_addr().to_string();
-        let mut transporter = Transporter::new(&host_addr);
+        let mut transporter = Transporter::with_security(&host_addr, security)?;
         let sender = transport_event_sender.clone();
         transporter.set_subscription_handler(move |msg| {
-            sender.send(TransportEvent::Subscription(msg)).unwrap();
+            let topic = msg.topic.to_string();
+            deliver_transport_event(&topic, TransportEvent::Subscription(msg), &sender);
         });
         let sender = transport_event_sender.clone();
         transporter.set_request_handler(move |msg| {
-            sender.send(TransportEvent::Request(msg)).unwrap();
+            let topic = msg.topic.to_string();
+            deliver_transport_event(&topic, TransportEvent::Request(msg), &sender);
         });
         let sender = transport_event_sender.clone();
         transporter.set_response_handler(move |msg| {
             sender.send(TransportEvent::Response(msg)).unwrap();
         });
+        let metrics = Arc::new(Mutex::new(TransportMetrics::default()));
+        let drop_metrics = metrics.clone();
+        transporter.set_drop_handler(move |topic| {
+            drop_metrics.lock().unwrap().topic_mut(topic).dropped += 1;
+        });
 
-        NodeSharedInner {
+        // Let the subscribers on this host bypass TCP.
+        if ipc {
+            let endpoint = ipc_endpoint(p_uuid);
+            match transporter.bind_ipc(&endpoint) {
+                Ok(_) => msg_discovery.set_ipc_address(Some(endpoint)),
+                Err(err) => error!("Failed to bind the IPC endpoint: {}", err),
+            }
+        }
+
+        Ok(NodeSharedInner {
             p_uuid: p_uuid.to_string(),
             discovery_event_receiver,
             discovery_event_sender,
@@ -301,8 +430,15 @@ impl NodeSharedInner {
             pending_requests: DispatcherStore::new(),
             response_dispatchers: DispatcherStore::new(),
             services: DispatcherStore::new(),
+            graph_event_senders: Vec::new(),
+            metrics,
+            ipc,
+            service_selection: ServiceSelection::default(),
+            coalesce_publish: false,
+            compressions: TopicCompressions::default(),
+            service_rounds: HashMap::new(),
             verbose,
-        }
+        })
     }
 
     fn node_event_sender(&self) -> UnboundedSender<NodeEvent> {
@@ -312,11 +448,11 @@ impl NodeSharedInner {
     async fn run(&mut self) {
         self.msg_discovery.start();
         self.srv_discovery.start();
-        self.transporter.start();
 
-        loop {
+        'run: loop {
             select! {
                 Some(discovery_event) = self.discovery_event_receiver.recv() => {
+                    self.notify_graph_event(&discovery_event);
                     match discovery_event {
                         DiscoveryEvent::Connection(discovery_publisher) => {
                             self.on_connection(discovery_publisher);
@@ -340,25 +476,38 @@ impl NodeSharedInner {
                 }
 
                 Some(node_event) = self.node_event_receiver.recv() => {
-                    match node_event {
-                        NodeEvent::Advertise(discovery_publisher) => {
-                            self.on_advertise(discovery_publisher);
-                        }
-                        NodeEvent::AdvertiseService(discovery_publisher,
-                            sender) => {
-                            self.on_advertise_service(discovery_publisher, sender);
-                        }
-                        NodeEvent::Subscribe(args) => {
-                            self.on_subscribe(args);
-                        }
-                        NodeEvent::Publish(msg) => {
-                            self.on_publish(msg);
-                        }
-                        NodeEvent::Request(msg, sender) => {
-                            self.on_request(msg, sender);
-                        }
-                        NodeEvent::Reply(msg) => {
-                            self.on_reply(msg);
+                    let (node_event, next_event) = self.coalesce(node_event);
+                    for node_event in std::iter::once(node_event).chain(next_event) {
+                        match node_event {
+                            NodeEvent::Advertise(discovery_publisher) => {
+                                self.on_advertise(discovery_publisher);
+                            }
+                            NodeEvent::AdvertiseService(discovery_publisher,
+                                sender) => {
+                                self.on_advertise_service(discovery_publisher, sender);
+                            }
+                            NodeEvent::Subscribe(args) => {
+                                self.on_subscribe(args);
+                            }
+                            NodeEvent::Publish(msg) => {
+                                self.on_publish(msg);
+                            }
+                            NodeEvent::PublishBatch(msgs) => {
+                                self.on_publish_batch(msgs);
+                            }
+                            NodeEvent::Request(msg, sender) => {
+                                self.on_request(msg, sender);
+                            }
+                            NodeEvent::Reply(msg) => {
+                                self.on_reply(msg);
+                            }
+                            NodeEvent::GraphEvents(sender) => {
+                                self.graph_event_senders.push(sender);
+                            }
+                            NodeEvent::Shutdown => {
+                                self.on_shutdown().await;
+                                break 'run;
+                            }
                         }
                     }
                 }
@@ -380,12 +529,48 @@ impl NodeSharedInner {
         }
     }
 
+    // Drain the publications queued behind a publication into one batch.
+    // It also returns the first other event found, to be handled next.
+    fn coalesce(&mut self, node_event: NodeEvent) -> (NodeEvent, Option<NodeEvent>) {
+        if !self.coalesce_publish {
+            return (node_event, None);
+        }
+        let mut batch = match node_event {
+            NodeEvent::Publish(msg) => vec![msg],
+            NodeEvent::PublishBatch(msgs) => msgs,
+            node_event => return (node_event, None),
+        };
+        let mut next_event = None;
+        while batch.len() < MAX_COALESCED_MSGS {
+            match self.node_event_receiver.try_recv() {
+                Ok(NodeEvent::Publish(msg)) => batch.push(msg),
+                Ok(NodeEvent::PublishBatch(msgs)) => batch.extend(msgs),
+                Ok(node_event) => {
+                    next_event = Some(node_event);
+                    break;
+                }
+                Err(_) => break,
+            }
+        }
+        (NodeEvent::PublishBatch(batch), next_event)
+    }
+
+    fn notify_graph_event(&mut self, discovery_event: &DiscoveryEvent) {
+        if self.graph_event_senders.is_empty() {
+            return;
+        }
+        let graph_event = discovery_event.graph_event();
+        // Drop the senders whose receivers have gone away.
+        self.graph_event_senders
+            .retain(|sender| sender.send(graph_event.clone()).is_ok());
+    }
+
     // Discovery Event Handler
     fn on_connection(&mut self, mut discovery_publisher: DiscoveryPublisher) {
         trace!("on_connection");
 
         let topic = discovery_publisher.topic.to_string();
-        let address = discovery_publisher.address.to_string();
+        let address = self.subscriber_address(&discovery_publisher);
         let p_uuid = discovery_publisher.process_uuid.to_string();
 
         if self.verbose {
@@ -399,8 +584,13 @@ impl NodeSharedInner {
         }
 
         // Subscribe to the topic.
-        self.transporter
-            .subscribe(Some(&address), None, Some(&topic), None);
+        if let Err(err) = self
+            .transporter
+            .subscribe(Some(&address), None, Some(&topic), None)
+        {
+            error!("Failed to subscribe: {}", err);
+            return;
+        }
 
         // Create a new publisher.
         let message_publisher = match discovery_publisher.pub_type {
@@ -421,6 +611,19 @@ impl NodeSharedInner {
             }
         }
     }
+    // Use the IPC endpoint of a publisher on this host, TCP otherwise.
+    fn subscriber_address(&self, discovery_publisher: &DiscoveryPublisher) -> String {
+        if self.ipc {
+            let p_uuid = discovery_publisher.process_uuid.as_str();
+            if let Some(address) = self.msg_discovery.ipc_address(p_uuid) {
+                // The socket file is only visible from the same host.
+                if Path::new(address.trim_start_matches("ipc://")).exists() {
+                    return address;
+                }
+            }
+        }
+        discovery_publisher.address.to_string()
+    }
     fn on_disconnection(&mut self, discovery_publisher: DiscoveryPublisher) {
         trace!("on_disconnection");
         let topic = discovery_publisher.topic.as_str();
@@ -500,35 +703,85 @@ impl NodeSharedInner {
         let topic = discovery_publisher.topic.to_string();
         let request_type = Some(service_publisher.request_type.as_str());
         let response_type = Some(service_publisher.response_type.as_str());
-        let replier_address = discovery_publisher.address.as_str();
-        let replier_id = service_publisher.socket_id.as_str();
+        let provider = ServiceProvider {
+            address: discovery_publisher.address.to_string(),
+            socket_id: service_publisher.socket_id.to_string(),
+            process_uuid: discovery_publisher.process_uuid.to_string(),
+        };
 
         if self.verbose {
             info!("Service call connection callback");
             DiscoveryStore::print_publisher(&discovery_publisher);
         }
 
-        self.send_pending_remote_reqs(
-            &topic,
-            request_type,
-            response_type,
-            replier_address,
-            replier_id,
-        );
+        // Requests without type names take the ones of this provider.
+        if let Some(requests) = self.pending_requests.get_for_topic(&topic) {
+            for request in requests {
+                let matches = |pending: Option<&str>, provided: &str| {
+                    pending.is_none_or(|t| t.is_empty() || t == provided)
+                };
+                if request.unresolved()
+                    && matches(request.request_type(), &service_publisher.request_type)
+                    && matches(request.response_type(), &service_publisher.response_type)
+                {
+                    request.resolve_types(
+                        &service_publisher.request_type,
+                        &service_publisher.response_type,
+                    );
+                }
+            }
+        }
+
+        self.send_pending_remote_reqs(&topic, request_type, response_type, &[provider]);
     }
-    fn on_srv_disconnection(&self, discovery_publisher: DiscoveryPublisher) {
+    fn on_srv_disconnection(&mut self, discovery_publisher: DiscoveryPublisher) {
         trace!("on_srv_disconnection");
         // Remove the address from the list of connected addresses.
         let address = discovery_publisher.address.as_str();
+        let process_uuid = discovery_publisher.process_uuid.as_str();
         self.transporter.srv_disconnect(address);
 
         if self.verbose {
             info!("Service call disconnection callback");
             DiscoveryStore::print_publisher(&discovery_publisher);
         }
+
+        // Move the requests waiting for this replier to another provider.
+        let orphans = self
+            .response_dispatchers
+            .remove_by_replier(address, process_uuid);
+        for dispatcher in orphans {
+            let (request_message, sender) = match dispatcher.into_request() {
+                Some(request) => request,
+                None => continue,
+            };
+            let providers: Vec<ServiceProvider> = self
+                .service_providers(
+                    &request_message.topic,
+                    &request_message.req_type,
+                    &request_message.res_type,
+                )
+                .into_iter()
+                .filter(|p| p.address != address && p.process_uuid != process_uuid)
+                .collect();
+            // Without providers the sender is dropped and the requester fails.
+            self.send_remote_request(request_message, sender, &providers);
+        }
     }
 
     // Node Event Handler
+    async fn on_shutdown(&mut self) {
+        trace!("on_shutdown");
+        if let Err(err) = self.msg_discovery.unadvertise_all() {
+            error!("Failed to unadvertise topics: {}", err);
+        }
+        if let Err(err) = self.srv_discovery.unadvertise_all() {
+            error!("Failed to unadvertise services: {}", err);
+        }
+        self.msg_discovery.stop().await;
+        self.srv_discovery.stop().await;
+        self.transporter.stop().await;
+    }
     fn on_advertise(&mut self, mut discovery_publisher: DiscoveryPublisher) {
         trace!("on_advertise");
         discovery_publisher.process_uuid = self.p_uuid.clone();
@@ -583,6 +836,19 @@ impl NodeSharedInner {
         if let Err(err) = self.subscribers.register(subscriber) {
             error!("Failed to register subscriber: {}", err);
         }
+        let discovery_subscriber = DiscoveryPublisher {
+            topic: topic.to_string(),
+            process_uuid: process_uuid.to_string(),
+            node_uuid: node_uuid.to_string(),
+            pub_type: Some(DiscoveryPubType::MsgPub(DiscoveryMsgPublisher {
+                msg_type: msg_type.to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        if let Err(err) = self.msg_discovery.register_subscriber(discovery_subscriber) {
+            debug!("Failed to register subscriber: {}", err);
+        }
         if let Err(err) = self.msg_discovery.discover(topic) {
             debug!("Failed to discover: {}", err);
         }
@@ -590,25 +856,96 @@ impl NodeSharedInner {
     fn on_publish(&mut self, msg: PublishMessage) {
         trace!("on_publish {:?}", msg);
 
+        let mut has_remote = false;
         if let Some(dispatchers) = self
             .subscribers
             .filter(&msg.topic, Some(&msg.msg_type), None)
         {
             for mut dispatcher in dispatchers {
-                let publish_message = msg.clone();
                 if dispatcher.is_remote() {
-                    // Send the message to the remote subscriber.
-                    if let Err(err) = self.transporter.publish(publish_message) {
-                        error!("Failed to publish: {}", err);
-                    }
-                } else {
+                    has_remote = true;
+                } else if let Err(err) = dispatcher.dispatch(msg.clone()) {
                     // Send the message to the local subscriber.
-                    dispatcher
-                        .dispatch(publish_message)
-                        .expect("Failed to dispatch message");
+                    debug!("Failed to dispatch a message of [{}]: {}", msg.topic, err);
                 }
             }
         }
+        if !has_remote {
+            return;
+        }
+        // The PUB socket sends the message to every remote subscriber at once.
+        // Only remote subscribers receive it compressed.
+        let compression = self.compressions.get(&msg.topic);
+        let topic = msg.topic.to_string();
+        let publish_message = PublishMessage {
+            data: compression.compress(&msg.data),
+            ..msg
+        };
+        match self.transporter.publish(publish_message) {
+            Ok(true) => {}
+            Ok(false) => {
+                debug!("A subscriber dropped a message of [{}]", topic);
+                self.metrics.lock().unwrap().topic_mut(&topic).dropped += 1;
+            }
+            Err(err) => {
+                error!("Failed to publish: {}", err);
+                self.metrics.lock().unwrap().topic_mut(&topic).send_errors += 1;
+            }
+        }
+    }
+    fn on_publish_batch(&mut self, mut msgs: Vec<PublishMessage>) {
+        trace!("on_publish_batch {} messages", msgs.len());
+        while !msgs.is_empty() {
+            // Consecutive messages of the same topic and type share the lookups.
+            let len = msgs
+                .iter()
+                .position(|m| m.topic != msgs[0].topic || m.msg_type != msgs[0].msg_type)
+                .unwrap_or(msgs.len());
+            let rest = msgs.split_off(len);
+            self.publish_messages(msgs);
+            msgs = rest;
+        }
+    }
+    // Publish messages of the same topic and type.
+    fn publish_messages(&mut self, msgs: Vec<PublishMessage>) {
+        let topic = msgs[0].topic.to_string();
+        let msg_type = msgs[0].msg_type.to_string();
+        let mut has_remote = false;
+        if let Some(dispatchers) = self.subscribers.filter(&topic, Some(&msg_type), None) {
+            for dispatcher in dispatchers {
+                if dispatcher.is_remote() {
+                    has_remote = true;
+                    continue;
+                }
+                // Send the messages to the local subscriber.
+                for msg in &msgs {
+                    if let Err(err) = dispatcher.dispatch(msg.clone()) {
+                        debug!("Failed to dispatch a message of [{}]: {}", topic, err);
+                        break;
+                    }
+                }
+            }
+        }
+        if !has_remote {
+            return;
+        }
+        // The PUB socket sends the messages to every remote subscriber at once.
+        let compression = self.compressions.get(&topic);
+        let remote_msgs: Vec<_> = msgs
+            .into_iter()
+            .map(|msg| PublishMessage {
+                data: compression.compress(&msg.data),
+                ..msg
+            })
+            .collect();
+        let failures = self.transporter.publish_batch(&remote_msgs);
+        if failures.errors > 0 {
+            error!("Failed to publish {} messages", failures.errors);
+        }
+        let mut metrics = self.metrics.lock().unwrap();
+        let topic_metrics = metrics.topic_mut(&topic);
+        topic_metrics.dropped += failures.dropped as u64;
+        topic_metrics.send_errors += failures.errors as u64;
     }
     fn on_request(
         &mut self,
@@ -617,6 +954,9 @@ impl NodeSharedInner {
     ) {
         trace!("on_request {:?}", request_message);
         request_message.req_uuid = uuid::Uuid::new_v4().to_string();
+        if request_message.req_type.is_empty() || request_message.res_type.is_empty() {
+            self.resolve_service_types(&mut request_message);
+        }
 
         let req_msg = request_message.clone();
         let topic = req_msg.topic.as_str();
@@ -639,26 +979,11 @@ impl NodeSharedInner {
             error!("Failed to register dispatcher: {}", err);
         }
 
-        if let Some(discovery_publishers) = self.srv_discovery.publishers(&topic) {
-            for discovery_publisher in discovery_publishers {
-                let service_publisher = match discovery_publisher.pub_type {
-                    Some(DiscoveryPubType::SrvPub(ref srv_pub)) => srv_pub,
-                    _ => return,
-                };
-                if Some(service_publisher.request_type.as_str()) == request_type
-                    && Some(service_publisher.response_type.as_str()) == response_type
-                {
-                    let replier_address = discovery_publisher.address.as_str();
-                    let replier_id = service_publisher.socket_id.as_str();
-                    self.send_pending_remote_reqs(
-                        &topic,
-                        request_type,
-                        response_type,
-                        replier_address,
-                        replier_id,
-                    );
-                    break;
-                }
+        if self.srv_discovery.publishers(topic).is_some() {
+            let providers =
+                self.service_providers(topic, &req_msg.req_type, &req_msg.res_type);
+            if !providers.is_empty() {
+                self.send_pending_remote_reqs(topic, request_type, response_type, &providers);
             }
         } else {
             // Discover the service.
@@ -696,8 +1021,16 @@ impl NodeSharedInner {
     }
 
     // Transport Event Handler
-    fn on_subscription(&mut self, msg: PublishMessage) {
+    fn on_subscription(&mut self, mut msg: PublishMessage) {
         trace!("on_subscription {:?}", msg);
+        match Compression::decompress(std::mem::take(&mut msg.data)) {
+            Ok(data) => msg.data = data,
+            Err(err) => {
+                error!("Failed to decompress message: {}", err);
+                self.metrics.lock().unwrap().topic_mut(&msg.topic).decode_failures += 1;
+                return;
+            }
+        }
         if let Some(mut subscribers) =
             self.subscribers
                 .filter(&msg.topic, Some(&msg.msg_type), None)
@@ -752,45 +1085,242 @@ impl NodeSharedInner {
         topic: &str,
         request_type: Option<&str>,
         response_type: Option<&str>,
-        replier_address: &str,
-        replier_id: &str,
+        providers: &[ServiceProvider],
     ) {
         if self.verbose {
-            info!("Found a service call at [{}]", replier_address);
+            for provider in providers {
+                info!("Found a service call at [{}]", provider.address);
+            }
         }
 
-        if let Some(requests) = self
+        let mut requests = vec![];
+        if let Some(pending_requests) = self
             .pending_requests
             .filter(topic, request_type, response_type)
         {
-            for request in requests {
-                let mut request_message = match request.message() {
+            for request in pending_requests {
+                let request_message = match request.message() {
                     Some(request_message) => request_message,
                     None => continue,
                 };
                 let sender = match request.sender() {
-                    Some(sender) => Some(sender),
+                    Some(sender) => sender,
                     None => continue,
                 };
-                request_message.replier_address = Some(replier_address.to_string());
-                request_message.replier_id = replier_id.to_string();
-
-                let dispatcher = ResponseDispatcher::new(&request_message, sender);
-                if let Err(err) = self.response_dispatchers.register(dispatcher) {
-                    error!("Failed to register dispatcher: {}", err);
+                requests.push((request_message, sender));
+            }
+        }
+        for (request_message, sender) in requests {
+            self.send_remote_request(request_message, sender, providers);
+        }
+        self.pending_requests.clean(None);
+    }
+    // Send a request to the first provider that accepts it.
+    fn send_remote_request(
+        &mut self,
+        mut request_message: RequestMessage,
+        sender: oneshot::Sender<ReplyMessage>,
+        providers: &[ServiceProvider],
+    ) {
+        for provider in providers {
+            // Discovery may have removed it since the list was made.
+            if !self.is_provider_known(&request_message.topic, provider) {
+                debug!(
+                    "Skip the removed provider of [{}] at [{}]",
+                    request_message.topic, provider.address
+                );
+                continue;
+            }
+            request_message.replier_address = Some(provider.address.to_string());
+            request_message.replier_id = provider.socket_id.to_string();
+            match self.transporter.request(request_message.clone()) {
+                Ok(_) => {
+                    let dispatcher = ResponseDispatcher::new(&request_message, Some(sender))
+                        .with_replier(request_message, &provider.process_uuid);
+                    if let Err(err) = self.response_dispatchers.register(dispatcher) {
+                        error!("Failed to register dispatcher: {}", err);
+                    }
+                    return;
                 }
-                if let Err(err) = self.transporter.request(request_message) {
-                    error!("Failed to request: {}", err);
+                Err(err) => debug!(
+                    "Failed to request [{}] at [{}]: {}",
+                    request_message.topic, provider.address, err
+                ),
+            }
+        }
+        error!(
+            "Failed to request [{}]: no service provider is reachable",
+            request_message.topic
+        );
+    }
+    // Fill the type names missing in a request with the ones of a provider.
+    fn resolve_service_types(&mut self, request_message: &mut RequestMessage) {
+        let matches = |requested: &str, provided: &str| {
+            requested.is_empty() || requested == provided
+        };
+        let local = self
+            .services
+            .get_for_topic(&request_message.topic)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|service| {
+                Some((
+                    service.request_type()?.to_string(),
+                    service.response_type()?.to_string(),
+                ))
+            });
+        let remote = self
+            .srv_discovery
+            .publishers(&request_message.topic)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|discovery_publisher| match discovery_publisher.pub_type {
+                Some(DiscoveryPubType::SrvPub(srv_pub)) => {
+                    Some((srv_pub.request_type, srv_pub.response_type))
+                }
+                _ => None,
+            });
+        let types = local.chain(remote).find(|(req_type, res_type)| {
+            matches(&request_message.req_type, req_type)
+                && matches(&request_message.res_type, res_type)
+        });
+        if let Some((req_type, res_type)) = types {
+            request_message.req_type = req_type;
+            request_message.res_type = res_type;
+        }
+    }
+    // Whether discovery still knows the provider of a service.
+    fn is_provider_known(&self, topic: &str, provider: &ServiceProvider) -> bool {
+        self.srv_discovery
+            .publishers(topic)
+            .unwrap_or_default()
+            .iter()
+            .any(|p| p.process_uuid == provider.process_uuid && p.address == provider.address)
+    }
+    // Remote providers of a service, ordered by the selection policy.
+    fn service_providers(
+        &mut self,
+        topic: &str,
+        request_type: &str,
+        response_type: &str,
+    ) -> Vec<ServiceProvider> {
+        let mut providers: Vec<ServiceProvider> = self
+            .srv_discovery
+            .publishers(topic)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|discovery_publisher| match discovery_publisher.pub_type {
+                Some(DiscoveryPubType::SrvPub(ref srv_pub))
+                    if srv_pub.request_type == request_type
+                        && srv_pub.response_type == response_type =>
+                {
+                    Some(ServiceProvider {
+                        address: discovery_publisher.address.to_string(),
+                        socket_id: srv_pub.socket_id.to_string(),
+                        process_uuid: discovery_publisher.process_uuid.to_string(),
+                    })
                 }
+                _ => None,
+            })
+            .collect();
+        let len = providers.len();
+        if len < 2 {
+            return providers;
+        }
+
+        match self.service_selection {
+            ServiceSelection::First => {}
+            ServiceSelection::RoundRobin => {
+                let round = self.service_rounds.entry(topic.to_string()).or_default();
+                providers.rotate_left(*round % len);
+                *round = round.wrapping_add(1);
+            }
+            ServiceSelection::Random => {
+                providers.rotate_left(rand::thread_rng().gen_range(0..len));
+            }
+            ServiceSelection::PreferLocal => {
+                let host_addr = *self.msg_discovery.host_addr();
+                let interfaces = self.msg_discovery.host_interfaces();
+                prefer_hosts(&mut providers, |host| is_local_host(host, host_addr, interfaces));
+            }
+            ServiceSelection::PreferSameHost => {
+                let host_addr = self.msg_discovery.host_addr().to_string();
+                prefer_hosts(&mut providers, |host| host == host_addr);
             }
         }
-        self.pending_requests.clean(None);
+        providers
     }
 }
 
+/// A remote process providing a service.
+#[derive(Debug, Clone)]
+struct ServiceProvider {
+    address: String,
+    socket_id: String,
+    process_uuid: String,
+}
+
+/// Move the providers on the hosts matching `prefer` first, in the same order.
+fn prefer_hosts(providers: &mut [ServiceProvider], prefer: impl Fn(&str) -> bool) {
+    providers.sort_by_key(|p| !prefer(address_host(&p.address)));
+}
+
+/// Host of a `tcp://host:port` address.
+fn address_host(address: &str) -> &str {
+    let address = address.trim_start_matches("tcp://");
+    address.rsplit_once(':').map(|(host, _)| host).unwrap_or(address)
+}
+
+/// Whether a host is this machine: a loopback address, `localhost` or an
+/// address of this host.
+fn is_local_host(host: &str, host_addr: Ipv4Addr, interfaces: &[Ipv4Addr]) -> bool {
+    if host == "localhost" {
+        return true;
+    }
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => ip.is_loopback() || ip == host_addr || interfaces.contains(&ip),
+        Ok(ip) => ip.is_loopback(),
+        Err(_) => false,
+    }
+}
+
+/// Send an event received from a remote process to the main loop.
+#[cfg(not(feature = "fault-injection"))]
+fn deliver_transport_event(
+    _topic: &str,
+    event: TransportEvent,
+    sender: &UnboundedSender<TransportEvent>,
+) {
+    sender.send(event).unwrap();
+}
+
+/// Send an event received from a remote process to the main loop, through
+/// the faults configured for its topic.
+#[cfg(feature = "fault-injection")]
+fn deliver_transport_event(
+    topic: &str,
+    event: TransportEvent,
+    sender: &UnboundedSender<TransportEvent>,
+) {
+    let sender = sender.clone();
+    crate::fault::inject(topic, event, move |event| {
+        // The main loop may be gone when a delayed event is delivered.
+        let _ = sender.send(event);
+    });
+}
+
+/// IPC endpoint of the publisher of a process.
+fn ipc_endpoint(p_uuid: &str) -> String {
+    let path = env::temp_dir().join(format!("rgz-{}.ipc", p_uuid));
+    format!("ipc://{}", path.display())
+}
+
 #[cfg(test)]
 mod tests {
     use prost::Message;
+    use rgz_msgs::GzMessage;
+    use std::collections::HashSet;
     use std::time::{Duration, SystemTime};
     use tokio::time;
     // use tracing::subscriber;
@@ -816,7 +1346,8 @@ mod tests {
     const P_UUID2: &str = "00000000-0000-0000-0000-000000000021";
     const N_UUID2: &str = "00000000-0000-0000-0000-000000000022";
 
-    #[derive(Clone, PartialEq, ::prost::Message)]
+    #[derive(Clone, PartialEq, ::prost::Message, GzMessage)]
+    #[gz(type_name = "Person")]
     struct Person {
         #[prost(string, tag = "1")]
         pub name: ::prost::alloc::string::String,
@@ -827,15 +1358,15 @@ mod tests {
     // Check that a node can pub & sub to the same process.
     #[tokio::test]
     async fn test_pub_sub_same_process() {
-        tracing_subscriber::fmt()
+        let _ = tracing_subscriber::fmt()
             .with_max_level(tracing::Level::DEBUG)
-            .init();
+            .try_init();
 
         let mut node_shared = NodeShared::new();
         node_shared.p_uuid = P_UUID1.to_string();
-        node_shared.start();
+        node_shared.start().unwrap();
 
-        let msg_type = "Person";
+        let msg_type = Person::TYPE_NAME;
         let message_publisher = DiscoveryMsgPublisher {
             ctrl: "unused".to_string(),
             msg_type: msg_type.to_string(),
@@ -903,18 +1434,18 @@ mod tests {
     // Check that a node can pub & sub to another process.
     #[tokio::test]
     async fn test_pub_sub_another_process() {
-        tracing_subscriber::fmt()
+        let _ = tracing_subscriber::fmt()
             .with_max_level(tracing::Level::INFO)
-            .init();
+            .try_init();
 
-        let msg_type = "Person";
+        let msg_type = Person::TYPE_NAME;
 
         let mut node_shared1 = NodeShared::new();
         node_shared1.p_uuid = P_UUID1.to_string();
         node_shared1.discovery_ip = IP.to_string();
         node_shared1.msg_disc_port = MSG_PORT;
         node_shared1.verbose = true;
-        node_shared1.start();
+        node_shared1.start().unwrap();
         let message_publisher = DiscoveryMsgPublisher {
             ctrl: "unused".to_string(),
             msg_type: msg_type.to_string(),
@@ -938,7 +1469,7 @@ mod tests {
         node_shared2.discovery_ip = IP.to_string();
         node_shared2.msg_disc_port = MSG_PORT;
         node_shared2.verbose = true;
-        node_shared2.start();
+        node_shared2.start().unwrap();
 
         let (tx, mut rx) = mpsc::unbounded_channel::<PublishMessage>();
         node_shared2
@@ -976,24 +1507,613 @@ mod tests {
         drop(node_shared1);
         time::sleep(Duration::from_millis(1000)).await;
     }
+
+    // Check that a remote advertisement is reported as a graph event.
+    #[tokio::test]
+    async fn test_graph_events() {
+        let mut node_shared1 = NodeShared::new();
+        node_shared1.p_uuid = P_UUID1.to_string();
+        node_shared1.discovery_ip = IP.to_string();
+        node_shared1.msg_disc_port = MSG_PORT;
+        node_shared1.start().unwrap();
+
+        let mut node_shared2 = NodeShared::new();
+        node_shared2.p_uuid = P_UUID2.to_string();
+        node_shared2.discovery_ip = IP.to_string();
+        node_shared2.msg_disc_port = MSG_PORT;
+        node_shared2.start().unwrap();
+        let mut graph_events = node_shared2.graph_events().unwrap();
+
+        time::sleep(Duration::from_millis(100)).await;
+
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: "Person".to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        node_shared1
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let event = time::timeout(Duration::from_millis(1000), graph_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match event {
+            GraphEvent::PublisherAppeared(endpoint) => {
+                assert_eq!(endpoint.topic, TOPIC);
+                assert_eq!(endpoint.msg_type, "Person");
+                assert_eq!(endpoint.process_uuid, P_UUID1);
+                assert_eq!(endpoint.node_uuid, N_UUID1);
+            }
+            _ => panic!("Unexpected graph event: {:?}", event),
+        }
+    }
+
+    // Domains of a process are linked by the in-memory bus of their port only.
+    #[tokio::test]
+    async fn test_in_memory_backend() {
+        let context = |domain: &str, msg_disc_port: u16| {
+            let mut context = TransportContext::new();
+            context.set_domain(domain);
+            context.set_discovery_backend(DiscoveryBackendType::InMemory);
+            context.set_msg_disc_port(msg_disc_port);
+            context.set_srv_disc_port(msg_disc_port + 1);
+            context
+        };
+        NodeShared::init(context("in_memory_pub", 11417)).unwrap();
+        NodeShared::init(context("in_memory_sub", 11417)).unwrap();
+        NodeShared::init(context("in_memory_other", 11419)).unwrap();
+        assert!(NodeShared::init(context("in_memory_pub", 11417)).is_err());
+
+        let publisher = NodeShared::instance("in_memory_pub");
+        let subscriber = NodeShared::instance("in_memory_sub");
+        let other = NodeShared::instance("in_memory_other");
+        let p_uuid = publisher.lock().unwrap().p_uuid.clone();
+        assert_ne!(p_uuid, subscriber.lock().unwrap().p_uuid);
+        let mut graph_events = subscriber.lock().unwrap().graph_events().unwrap();
+        let mut other_graph_events = other.lock().unwrap().graph_events().unwrap();
+
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: "Person".to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        publisher
+            .lock()
+            .unwrap()
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let event = time::timeout(Duration::from_millis(1000), graph_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match event {
+            GraphEvent::PublisherAppeared(endpoint) => {
+                assert_eq!(endpoint.topic, TOPIC);
+                assert_eq!(endpoint.process_uuid, p_uuid);
+            }
+            _ => panic!("Unexpected graph event: {:?}", event),
+        }
+        let other_event =
+            time::timeout(Duration::from_millis(300), other_graph_events.recv()).await;
+        assert!(other_event.is_err());
+    }
+
+    // Check that subscribers pick the IPC endpoint of publishers on this host.
+    #[cfg(not(feature = "zmtp"))]
+    #[tokio::test]
+    async fn test_ipc_address() {
+        let security = Security::default();
+        let backend = DiscoveryBackendType::InMemory;
+        let inner1 = NodeSharedInner::new(
+            P_UUID1,
+            IP,
+            MSG_PORT,
+            SRV_PORT,
+            &backend,
+            security.clone(),
+            true,
+            false,
+        )
+        .unwrap();
+        let inner2 = NodeSharedInner::new(
+            P_UUID2, IP, MSG_PORT, SRV_PORT, &backend, security, true, false,
+        )
+        .unwrap();
+
+        let endpoint = ipc_endpoint(P_UUID1);
+        assert!(Path::new(endpoint.trim_start_matches("ipc://")).exists());
+
+        let publisher = DiscoveryPublisher {
+            topic: TOPIC.to_string(),
+            address: ADDR1.to_string(),
+            process_uuid: P_UUID1.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(inner2.subscriber_address(&publisher), ADDR1);
+
+        let store = inner2.msg_discovery.discovery_store();
+        store.lock().unwrap().set_ipc_address(P_UUID1, &endpoint);
+        assert_eq!(inner2.subscriber_address(&publisher), endpoint);
+
+        // A remote host announces a socket file that does not exist here.
+        store
+            .lock()
+            .unwrap()
+            .set_ipc_address(P_UUID1, "ipc:///nonexistent/rgz.ipc");
+        assert_eq!(inner2.subscriber_address(&publisher), ADDR1);
+
+        drop(inner1);
+    }
+
+    #[tokio::test]
+    async fn test_service_providers() {
+        let mut inner = NodeSharedInner::new(
+            P_UUID2,
+            IP,
+            MSG_PORT,
+            SRV_PORT,
+            &DiscoveryBackendType::InMemory,
+            Security::default(),
+            false,
+            false,
+        )
+        .unwrap();
+        let store = inner.srv_discovery.discovery_store();
+        for (address, p_uuid) in [("tcp://10.0.0.1:12345", P_UUID1), (ADDR2, N_UUID1)] {
+            let service_publisher = DiscoverySrvPublisher {
+                socket_id: p_uuid.to_string(),
+                request_type: "Person".to_string(),
+                response_type: "Person".to_string(),
+            };
+            store
+                .lock()
+                .unwrap()
+                .add_publisher(DiscoveryPublisher {
+                    topic: SERVICE_NAME.to_string(),
+                    address: address.to_string(),
+                    process_uuid: p_uuid.to_string(),
+                    node_uuid: p_uuid.to_string(),
+                    scope: DiscoveryScope::All as i32,
+                    pub_type: Some(DiscoveryPubType::SrvPub(service_publisher)),
+                })
+                .unwrap();
+        }
+        let first_process = |inner: &mut NodeSharedInner| {
+            inner.service_providers(SERVICE_NAME, "Person", "Person")[0]
+                .process_uuid
+                .to_string()
+        };
+
+        inner.service_selection = ServiceSelection::PreferLocal;
+        assert_eq!(first_process(&mut inner), N_UUID1);
+
+        inner.service_selection = ServiceSelection::RoundRobin;
+        let first = first_process(&mut inner);
+        assert_ne!(first_process(&mut inner), first);
+        assert_eq!(first_process(&mut inner), first);
+
+        inner.service_selection = ServiceSelection::Random;
+        let firsts: HashSet<_> = (0..64).map(|_| first_process(&mut inner)).collect();
+        assert_eq!(firsts.len(), 2);
+        assert!(inner.service_providers(SERVICE_NAME, "Person", "Other").is_empty());
+
+        // A request waiting for a provider that disappeared fails at once.
+        store.lock().unwrap().del_publishers_by_process(P_UUID1).unwrap();
+        store.lock().unwrap().del_publishers_by_process(N_UUID1).unwrap();
+        let request_message = RequestMessage {
+            replier_address: Some(ADDR1.to_string()),
+            replier_id: P_UUID1.to_string(),
+            topic: SERVICE_NAME.to_string(),
+            requester_address: "".to_string(),
+            requester_id: "".to_string(),
+            node_uuid: N_UUID2.to_string(),
+            req_uuid: "req".to_string(),
+            data: vec![],
+            req_type: "Person".to_string(),
+            res_type: "Person".to_string(),
+        };
+        let (sender, receiver) = oneshot::channel::<ReplyMessage>();
+        let dispatcher = ResponseDispatcher::new(&request_message, Some(sender))
+            .with_replier(request_message.clone(), P_UUID1);
+        inner.response_dispatchers.register(dispatcher).unwrap();
+        inner.on_srv_disconnection(DiscoveryPublisher {
+            process_uuid: P_UUID1.to_string(),
+            ..Default::default()
+        });
+        assert!(receiver.await.is_err());
+
+        // Nor does it wait for a provider removed after the list was made.
+        let (sender, receiver) = oneshot::channel::<ReplyMessage>();
+        let removed = ServiceProvider {
+            address: ADDR2.to_string(),
+            socket_id: N_UUID1.to_string(),
+            process_uuid: N_UUID1.to_string(),
+        };
+        inner.send_remote_request(request_message, sender, &[removed]);
+        assert!(receiver.await.is_err());
+    }
+
+    #[test]
+    fn test_is_local_host() {
+        let host_addr = Ipv4Addr::new(192, 168, 1, 10);
+        let interfaces = [host_addr, Ipv4Addr::new(10, 0, 0, 2)];
+        for host in ["127.0.0.1", "127.0.1.1", "::1", "[::1]", "localhost"] {
+            assert!(is_local_host(host, host_addr, &[]), "{}", host);
+        }
+        for host in ["192.168.1.10", "10.0.0.2"] {
+            assert!(is_local_host(host, host_addr, &interfaces), "{}", host);
+        }
+        for host in ["10.0.0.1", "fe80::1", "example.com", ""] {
+            assert!(!is_local_host(host, host_addr, &interfaces), "{}", host);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalesce() {
+        let mut inner = NodeSharedInner::new(
+            P_UUID1,
+            IP,
+            MSG_PORT,
+            SRV_PORT,
+            &DiscoveryBackendType::InMemory,
+            Security::default(),
+            false,
+            false,
+        )
+        .unwrap();
+        inner.coalesce_publish = true;
+        let publish_message = |data: u8| PublishMessage {
+            topic: TOPIC.to_string(),
+            publisher_address: "unset".to_string(),
+            msg_type: "Person".to_string(),
+            data: vec![data],
+        };
+
+        let sender = inner.node_event_sender();
+        sender.send(NodeEvent::Publish(publish_message(1))).unwrap();
+        sender
+            .send(NodeEvent::PublishBatch(vec![publish_message(2), publish_message(3)]))
+            .unwrap();
+        sender.send(NodeEvent::Shutdown).unwrap();
+        sender.send(NodeEvent::Publish(publish_message(4))).unwrap();
+
+        let first = inner.node_event_receiver.recv().await.unwrap();
+        let (batch, next_event) = inner.coalesce(first);
+        match batch {
+            NodeEvent::PublishBatch(msgs) => {
+                let data: Vec<u8> = msgs.iter().map(|m| m.data[0]).collect();
+                assert_eq!(data, vec![1, 2, 3]);
+            }
+            _ => panic!("Expected a batch"),
+        }
+        assert!(matches!(next_event, Some(NodeEvent::Shutdown)));
+    }
+
+    // Check that two processes exchange messages through IPC.
+    #[cfg(not(feature = "zmtp"))]
+    #[tokio::test]
+    async fn test_pub_sub_ipc() {
+        let msg_type = Person::TYPE_NAME;
+
+        let mut node_shared1 = NodeShared::new();
+        node_shared1.p_uuid = P_UUID1.to_string();
+        node_shared1.msg_disc_port = 11323;
+        node_shared1.srv_disc_port = 11324;
+        node_shared1.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared1.ipc = true;
+        node_shared1.start().unwrap();
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: msg_type.to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        let sender = node_shared1
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let mut node_shared2 = NodeShared::new();
+        node_shared2.p_uuid = P_UUID2.to_string();
+        node_shared2.msg_disc_port = 11323;
+        node_shared2.srv_disc_port = 11324;
+        node_shared2.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared2.ipc = true;
+        node_shared2.start().unwrap();
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<PublishMessage>();
+        node_shared2
+            .subscribe(SubscribeArgs {
+                n_uuid: N_UUID2.to_string(),
+                topic: TOPIC.to_string(),
+                msg_type: msg_type.to_string(),
+                sender: tx,
+            })
+            .unwrap();
+
+        time::sleep(Duration::from_millis(300)).await;
+        let data = Person {
+            name: "Alice".to_string(),
+            id: 1234,
+        }
+        .encode_to_vec();
+        sender
+            .send(NodeEvent::Publish(PublishMessage {
+                topic: TOPIC.to_string(),
+                msg_type: msg_type.to_string(),
+                data,
+                publisher_address: "unset".to_string(),
+            }))
+            .unwrap();
+
+        let msg = time::timeout(Duration::from_millis(1000), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let person = Person::decode(&msg.data[..]).unwrap();
+        assert_eq!(person.name, "Alice");
+    }
+
+    // Check that subscribers decompress the messages of a compressed topic.
+    #[tokio::test]
+    async fn test_pub_sub_compression() {
+        let msg_type = Person::TYPE_NAME;
+
+        let mut node_shared1 = NodeShared::new();
+        node_shared1.p_uuid = P_UUID1.to_string();
+        node_shared1.msg_disc_port = 11325;
+        node_shared1.srv_disc_port = 11326;
+        node_shared1.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared1.start().unwrap();
+        let _compression = node_shared1
+            .register_compression(TOPIC, Compression::Lz4)
+            .unwrap();
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: msg_type.to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        let sender = node_shared1
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let mut node_shared2 = NodeShared::new();
+        node_shared2.p_uuid = P_UUID2.to_string();
+        node_shared2.msg_disc_port = 11325;
+        node_shared2.srv_disc_port = 11326;
+        node_shared2.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared2.start().unwrap();
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<PublishMessage>();
+        node_shared2
+            .subscribe(SubscribeArgs {
+                n_uuid: N_UUID2.to_string(),
+                topic: TOPIC.to_string(),
+                msg_type: msg_type.to_string(),
+                sender: tx,
+            })
+            .unwrap();
+
+        time::sleep(Duration::from_millis(300)).await;
+        let data = Person {
+            name: "Alice".repeat(100),
+            id: 1234,
+        }
+        .encode_to_vec();
+        sender
+            .send(NodeEvent::Publish(PublishMessage {
+                topic: TOPIC.to_string(),
+                msg_type: msg_type.to_string(),
+                data,
+                publisher_address: "unset".to_string(),
+            }))
+            .unwrap();
+
+        let msg = time::timeout(Duration::from_millis(1000), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let person = Person::decode(&msg.data[..]).unwrap();
+        assert_eq!(person.name, "Alice".repeat(100));
+    }
+
+    // Check that the subscribers of two remote nodes get each message once.
+    #[tokio::test]
+    async fn test_pub_sub_remote_nodes() {
+        let msg_type = Person::TYPE_NAME;
+
+        let mut node_shared1 = NodeShared::new();
+        node_shared1.p_uuid = P_UUID1.to_string();
+        node_shared1.msg_disc_port = 11327;
+        node_shared1.srv_disc_port = 11328;
+        node_shared1.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared1.start().unwrap();
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: msg_type.to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        let sender = node_shared1
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let mut node_shared2 = NodeShared::new();
+        node_shared2.p_uuid = P_UUID2.to_string();
+        node_shared2.msg_disc_port = 11327;
+        node_shared2.srv_disc_port = 11328;
+        node_shared2.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared2.start().unwrap();
+
+        let mut receivers = vec![];
+        for n_uuid in [N_UUID2, "node_uuid3"] {
+            let (tx, rx) = mpsc::unbounded_channel::<PublishMessage>();
+            node_shared2
+                .subscribe(SubscribeArgs {
+                    n_uuid: n_uuid.to_string(),
+                    topic: TOPIC.to_string(),
+                    msg_type: msg_type.to_string(),
+                    sender: tx,
+                })
+                .unwrap();
+            receivers.push(rx);
+        }
+
+        time::sleep(Duration::from_millis(300)).await;
+        let publish_message = |id: i32| PublishMessage {
+            topic: TOPIC.to_string(),
+            msg_type: msg_type.to_string(),
+            data: Person {
+                name: "Alice".to_string(),
+                id,
+            }
+            .encode_to_vec(),
+            publisher_address: "unset".to_string(),
+        };
+        sender.send(NodeEvent::Publish(publish_message(1))).unwrap();
+        sender
+            .send(NodeEvent::PublishBatch(vec![publish_message(2), publish_message(3)]))
+            .unwrap();
+
+        for rx in receivers.iter_mut() {
+            for id in 1..=3 {
+                let msg = time::timeout(Duration::from_millis(1000), rx.recv())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(Person::decode(&msg.data[..]).unwrap().id, id);
+            }
+        }
+        time::sleep(Duration::from_millis(200)).await;
+        for rx in receivers.iter_mut() {
+            assert!(rx.try_recv().is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let mut node_shared1 = NodeShared::new();
+        node_shared1.p_uuid = P_UUID1.to_string();
+        node_shared1.msg_disc_port = 11317;
+        node_shared1.srv_disc_port = 11318;
+        node_shared1.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared1.start().unwrap();
+
+        let mut node_shared2 = NodeShared::new();
+        node_shared2.p_uuid = P_UUID2.to_string();
+        node_shared2.msg_disc_port = 11317;
+        node_shared2.srv_disc_port = 11318;
+        node_shared2.discovery_backend = DiscoveryBackendType::InMemory;
+        node_shared2.start().unwrap();
+        let mut graph_events = node_shared2.graph_events().unwrap();
+
+        time::sleep(Duration::from_millis(100)).await;
+
+        let message_publisher = DiscoveryMsgPublisher {
+            ctrl: "unused".to_string(),
+            msg_type: "Person".to_string(),
+            throttled: false,
+            msgs_per_sec: u64::MAX,
+        };
+        node_shared1
+            .advertise(DiscoveryPublisher {
+                topic: TOPIC.to_string(),
+                address: "".to_string(),
+                process_uuid: "".to_string(),
+                node_uuid: N_UUID1.to_string(),
+                scope: DiscoveryScope::All as i32,
+                pub_type: Some(DiscoveryPubType::MsgPub(message_publisher)),
+            })
+            .unwrap();
+
+        let event = time::timeout(Duration::from_millis(1000), graph_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, GraphEvent::PublisherAppeared(_)));
+
+        let handle = node_shared1.stop().unwrap().unwrap();
+        time::timeout(Duration::from_millis(1000), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(node_shared1.advertise(DiscoveryPublisher::default()).is_err());
+
+        let event = time::timeout(Duration::from_millis(1000), graph_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match event {
+            GraphEvent::PublisherDisappeared(endpoint) => {
+                assert_eq!(endpoint.topic, TOPIC);
+                assert_eq!(endpoint.process_uuid, P_UUID1);
+            }
+            _ => panic!("Unexpected graph event: {:?}", event),
+        }
+        let event = time::timeout(Duration::from_millis(1000), graph_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event, GraphEvent::ProcessSilent(P_UUID1.to_string()));
+    }
     #[tokio::test]
     async fn test_req_res_same_process() {
-        tracing_subscriber::fmt()
+        let _ = tracing_subscriber::fmt()
             .with_max_level(tracing::Level::INFO)
-            .init();
+            .try_init();
 
         let now = SystemTime::now();
         let now_clone = now.clone();
 
         let mut node_shared = NodeShared::new();
         node_shared.p_uuid = P_UUID1.to_string();
-        node_shared.start();
+        node_shared.start().unwrap();
 
-        let msg_type = "Person";
+        let msg_type = Person::TYPE_NAME;
         let service_publisher = DiscoverySrvPublisher {
             socket_id: "".to_string(),
-            request_type: "Person".to_string(),
-            response_type: "Person".to_string(),
+            request_type: msg_type.to_string(),
+            response_type: msg_type.to_string(),
         };
         let pub_type = DiscoveryPubType::SrvPub(service_publisher);
         let discovery_publisher = DiscoveryPublisher {
@@ -1044,8 +2164,8 @@ mod tests {
                 node_uuid: N_UUID1.to_string(),
                 req_uuid: "unset".to_string(),
                 data,
-                req_type: "Person".to_string(),
-                res_type: "Person".to_string(),
+                req_type: msg_type.to_string(),
+                res_type: msg_type.to_string(),
             })
             .unwrap();
 
@@ -1068,7 +2188,7 @@ mod tests {
         node_shared1.discovery_ip = IP.to_string();
         node_shared1.srv_disc_port = SRV_PORT;
         node_shared1.verbose = true;
-        node_shared1.start();
+        node_shared1.start().unwrap();
         tokio::time::sleep(Duration::from_millis(100)).await;
 
         let mut node_shared2 = NodeShared::new();
@@ -1076,13 +2196,13 @@ mod tests {
         node_shared2.discovery_ip = IP.to_string();
         node_shared2.srv_disc_port = SRV_PORT;
         node_shared2.verbose = true;
-        node_shared2.start();
+        node_shared2.start().unwrap();
 
-        let msg_type = "Person";
+        let msg_type = Person::TYPE_NAME;
         let service_publisher = DiscoverySrvPublisher {
             socket_id: "".to_string(),
-            request_type: "Person".to_string(),
-            response_type: "Person".to_string(),
+            request_type: msg_type.to_string(),
+            response_type: msg_type.to_string(),
         };
         let pub_type = DiscoveryPubType::SrvPub(service_publisher);
         let discovery_publisher = DiscoveryPublisher {
@@ -1134,8 +2254,8 @@ mod tests {
                 node_uuid: N_UUID2.to_string(),
                 req_uuid: "unset".to_string(),
                 data,
-                req_type: "Person".to_string(),
-                res_type: "Person".to_string(),
+                req_type: msg_type.to_string(),
+                res_type: msg_type.to_string(),
             })
             .unwrap();
 
@@ -0,0 +1,268 @@
+use std::path::Path;
+#[cfg(not(feature = "zmtp"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(feature = "zmtp"))]
+use std::sync::Arc;
+#[cfg(not(feature = "zmtp"))]
+use std::thread::{self, JoinHandle};
+use std::{env, fs};
+
+use anyhow::{bail, Result};
+#[cfg(not(feature = "zmtp"))]
+use tracing::{debug, error};
+
+/// ZAP domain used by gz-transport for the PLAIN mechanism.
+#[cfg(not(feature = "zmtp"))]
+const ZAP_DOMAIN: &str = "gz_auth";
+
+/// Well-known endpoint of the ZAP handler inside a ZMQ context.
+#[cfg(not(feature = "zmtp"))]
+const ZAP_ENDPOINT: &str = "inproc://zeromq.zap.01";
+
+/// Timeout used for polling ZAP requests (ms.).
+#[cfg(not(feature = "zmtp"))]
+const ZAP_TIMEOUT: i64 = 250;
+
+/// Alphabet of the Z85 encoding (ZMQ RFC 32).
+const Z85_CHARS: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// User name and password for the ZMQ PLAIN mechanism.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlainCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl PlainCredentials {
+    pub fn new(username: &str, password: &str) -> Self {
+        PlainCredentials {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    /// Read `GZ_TRANSPORT_USERNAME` and `GZ_TRANSPORT_PASSWORD`, as gz-transport does.
+    pub fn from_env() -> Option<Self> {
+        let username = env::var("GZ_TRANSPORT_USERNAME").unwrap_or_default();
+        let password = env::var("GZ_TRANSPORT_PASSWORD").unwrap_or_default();
+        if username.is_empty() || password.is_empty() {
+            return None;
+        }
+        Some(PlainCredentials { username, password })
+    }
+}
+
+/// Keys for the ZMQ CURVE mechanism.
+///
+/// Publishers use `secret_key` as server key. Subscribers authenticate with
+/// `public_key`/`secret_key` and expect publishers to own `server_key`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveKeys {
+    pub public_key: Vec<u8>,
+    pub secret_key: Vec<u8>,
+    pub server_key: Vec<u8>,
+}
+
+impl CurveKeys {
+    /// Create the keys from their Z85 representation.
+    pub fn from_z85(public_key: &str, secret_key: &str, server_key: &str) -> Result<Self> {
+        Ok(CurveKeys {
+            public_key: decode_key(public_key)?,
+            secret_key: decode_key(secret_key)?,
+            server_key: decode_key(server_key)?,
+        })
+    }
+
+    /// Read the keys from files, each one containing a Z85 encoded key.
+    pub fn from_files<P: AsRef<Path>>(public_key: P, secret_key: P, server_key: P) -> Result<Self> {
+        Self::from_z85(
+            &fs::read_to_string(public_key)?,
+            &fs::read_to_string(secret_key)?,
+            &fs::read_to_string(server_key)?,
+        )
+    }
+}
+
+fn decode_key(z85: &str) -> Result<Vec<u8>> {
+    match z85_decode(z85.trim()) {
+        Some(key) if key.len() == 32 => Ok(key),
+        _ => bail!("Invalid CURVE key [{}]", z85.trim()),
+    }
+}
+
+fn z85_decode(z85: &str) -> Option<Vec<u8>> {
+    if !z85.len().is_multiple_of(5) {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(z85.len() * 4 / 5);
+    for chunk in z85.as_bytes().chunks(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = Z85_CHARS.iter().position(|z| z == c)?;
+            value = value * 85 + digit as u64;
+        }
+        if value > u32::MAX as u64 {
+            return None;
+        }
+        decoded.extend_from_slice(&(value as u32).to_be_bytes());
+    }
+    Some(decoded)
+}
+
+/// Security settings of the PUB/SUB sockets. CURVE takes precedence over PLAIN.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Security {
+    pub(crate) plain: Option<PlainCredentials>,
+    pub(crate) curve: Option<CurveKeys>,
+}
+
+#[cfg(not(feature = "zmtp"))]
+impl Security {
+    // Configure a socket that accepts connections (publisher).
+    pub(crate) fn apply_server(&self, socket: &zmq::Socket) -> Result<()> {
+        if let Some(curve) = self.curve.as_ref() {
+            if zmq::has("curve") != Some(true) {
+                bail!("libzmq was built without CURVE support");
+            }
+            socket.set_curve_server(true)?;
+            socket.set_curve_secretkey(&curve.secret_key)?;
+        } else if self.plain.is_some() {
+            socket.set_plain_server(true)?;
+            socket.set_zap_domain(ZAP_DOMAIN)?;
+        }
+        Ok(())
+    }
+
+    // Configure a socket that connects to a server (subscriber).
+    pub(crate) fn apply_client(&self, socket: &zmq::Socket) -> Result<()> {
+        if let Some(curve) = self.curve.as_ref() {
+            socket.set_curve_serverkey(&curve.server_key)?;
+            socket.set_curve_publickey(&curve.public_key)?;
+            socket.set_curve_secretkey(&curve.secret_key)?;
+        } else if let Some(plain) = self.plain.as_ref() {
+            socket.set_plain_username(Some(&plain.username))?;
+            socket.set_plain_password(Some(&plain.password))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the PLAIN credentials of incoming connections (ZMQ RFC 27).
+#[cfg(not(feature = "zmtp"))]
+pub(crate) struct ZapHandler {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(not(feature = "zmtp"))]
+impl ZapHandler {
+    pub(crate) fn start(context: &zmq::Context, credentials: PlainCredentials) -> Result<Self> {
+        let socket = context.socket(zmq::REP)?;
+        socket.set_linger(0)?;
+        socket.bind(ZAP_ENDPOINT)?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let running_clone = running.clone();
+        let handle = thread::spawn(move || {
+            while running_clone.load(Ordering::SeqCst) {
+                match socket.poll(zmq::POLLIN, ZAP_TIMEOUT) {
+                    Ok(0) => continue,
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!("ZAP poll failed: {}", e);
+                        break;
+                    }
+                }
+                if let Err(e) = Self::handle_request(&socket, &credentials) {
+                    error!("ZAP request failed: {}", e);
+                }
+            }
+            debug!("ZAP handler finished.");
+        });
+
+        Ok(ZapHandler {
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    fn handle_request(socket: &zmq::Socket, credentials: &PlainCredentials) -> Result<()> {
+        let frames = socket.recv_multipart(0)?;
+        if frames.len() < 6 {
+            bail!("Malformed ZAP request");
+        }
+        let version = &frames[0];
+        let request_id = &frames[1];
+        let domain = String::from_utf8_lossy(&frames[2]);
+        let mechanism = String::from_utf8_lossy(&frames[5]);
+
+        let accepted = domain == ZAP_DOMAIN
+            && mechanism == "PLAIN"
+            && frames.len() >= 8
+            && frames[6] == credentials.username.as_bytes()
+            && frames[7] == credentials.password.as_bytes();
+
+        let (status_code, status_text) = if accepted {
+            ("200", "OK")
+        } else {
+            debug!(
+                "Rejected ZAP request. domain: [{}], mechanism: [{}]",
+                domain, mechanism
+            );
+            ("400", "Invalid username or password")
+        };
+        let user_id = if accepted {
+            credentials.username.as_str()
+        } else {
+            ""
+        };
+        socket.send_multipart(
+            [
+                version.as_slice(),
+                request_id.as_slice(),
+                status_code.as_bytes(),
+                status_text.as_bytes(),
+                user_id.as_bytes(),
+                b"",
+            ],
+            0,
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn stop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                error!("ZAP handler thread panicked");
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "zmtp"))]
+impl Drop for ZapHandler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // Test vector of ZMQ RFC 32.
+        let decoded = z85_decode("HelloWorld").unwrap();
+        assert_eq!(
+            decoded,
+            vec![0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hello").is_some());
+        assert!(z85_decode("Hell").is_none());
+        assert!(z85_decode("Hell~").is_none());
+        assert!(CurveKeys::from_z85("HelloWorld", "HelloWorld", "HelloWorld").is_err());
+    }
+}
@@ -0,0 +1,1082 @@
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::io::unix::AsyncFd;
+use tokio::select;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Instant};
+use tracing::{debug, error};
+use zmq;
+
+use crate::security::{Security, ZapHandler};
+use crate::transport::{
+    PublishFailures, PublishMessage, ReplyMessage, RequestHandlerType, RequestMessage,
+    ResponseHandlerType, SubscribeEvent, SubscriptionHandlerType, DEFAULT_RCV_HWM, DEFAULT_SND_HWM,
+};
+
+/// Maximum time to wait for a new service connection (ms.).
+const CONNECT_TIMEOUT: u64 = 1000;
+
+/// Time between the attempts to send on a new service connection (ms.).
+const SEND_RETRY_IVL: u64 = 5;
+
+pub(crate) struct Transporter {
+    publisher: zmq::Socket,
+    // Shared with the tasks waiting for a new service connection.
+    requester: Arc<Mutex<zmq::Socket>>,
+
+    publisher_address: String,
+
+    requester_id: String,
+    requester_address: String,
+
+    replier_id: String,
+    replier_address: String,
+
+    connections: Arc<Mutex<HashSet<String>>>,
+    srv_connections: Arc<Mutex<HashSet<String>>>,
+
+    subscription_handler: Arc<Mutex<Option<SubscriptionHandlerType>>>,
+    request_handler: Arc<Mutex<Option<RequestHandlerType>>>,
+    response_handler: Arc<Mutex<Option<ResponseHandlerType>>>,
+
+    subscribe_evt_sender: Option<UnboundedSender<SubscribeEvent>>,
+    reply_msg_sender: Option<UnboundedSender<ReplyMessage>>,
+
+    // Sockets of the receive task, until it is started.
+    receive_sockets: Option<ReceiveSockets>,
+    // Receive task.
+    handle: Option<JoinHandle<()>>,
+
+    zap_handler: Option<ZapHandler>,
+}
+
+impl Transporter {
+    #[cfg(test)]
+    pub(crate) fn new(host_addr: &str) -> Result<Self> {
+        Self::with_security(host_addr, Security::default())
+    }
+
+    pub(crate) fn with_security(host_addr: &str, security: Security) -> Result<Self> {
+        let any_tcp = format!("tcp://{}:*", host_addr);
+        let context = zmq::Context::new();
+
+        // The ZAP handler must be ready before any connection is accepted.
+        let mut zap_handler = None;
+        if let (Some(plain), None) = (security.plain.as_ref(), security.curve.as_ref()) {
+            let handler = ZapHandler::start(&context, plain.clone())
+                .context("failed starting the ZAP handler")?;
+            zap_handler = Some(handler);
+        }
+
+        let publisher = context.socket(zmq::PUB)?;
+        let linger_val = 0;
+        publisher.set_linger(linger_val)?;
+        publisher.set_sndhwm(DEFAULT_SND_HWM)?;
+        security
+            .apply_server(&publisher)
+            .context("failed setting the publisher security")?;
+        publisher
+            .bind(&any_tcp)
+            .context("failed binding publisher")?;
+
+        let publisher_address = match publisher.get_last_endpoint() {
+            Ok(Ok(endpoint)) => endpoint,
+            _ => "".to_string(),
+        };
+        // The requester connects to the repliers, which act as servers.
+        let requester = context.socket(zmq::ROUTER)?;
+        requester.set_linger(linger_val)?;
+        requester.set_router_mandatory(true)?;
+        security
+            .apply_client(&requester)
+            .context("failed setting the requester security")?;
+        let requester_id = uuid::Uuid::new_v4().to_string();
+        let replier_id = uuid::Uuid::new_v4().to_string();
+
+        let receive_sockets =
+            ReceiveSockets::new(&context, &any_tcp, &requester_id, &replier_id, &security)?;
+        let requester_address = receive_sockets.requester_address();
+        let replier_address = receive_sockets.replier_address();
+
+        Ok(Transporter {
+            publisher,
+            requester: Arc::new(Mutex::new(requester)),
+            publisher_address,
+            requester_id,
+            requester_address,
+            replier_id,
+            replier_address,
+            connections: Arc::new(Mutex::new(HashSet::new())),
+            srv_connections: Arc::new(Mutex::new(HashSet::new())),
+            subscription_handler: Arc::new(Mutex::new(None)),
+            request_handler: Arc::new(Mutex::new(None)),
+            response_handler: Arc::new(Mutex::new(None)),
+            subscribe_evt_sender: None,
+            reply_msg_sender: None,
+            receive_sockets: Some(receive_sockets),
+            handle: None,
+            zap_handler,
+        })
+    }
+
+    pub(crate) fn publisher_address(&self) -> String {
+        self.publisher_address.clone()
+    }
+
+    /// Also accept subscribers on a unix domain socket, e.g. `ipc:///tmp/pub.ipc`.
+    pub(crate) fn bind_ipc(&self, endpoint: &str) -> Result<()> {
+        self.publisher.bind(endpoint)?;
+        debug!("Publisher bound to [{}]", endpoint);
+        Ok(())
+    }
+
+    pub(crate) fn replier_address(&self) -> String {
+        self.replier_address.clone()
+    }
+    pub(crate) fn replier_id(&self) -> String {
+        self.replier_id.clone()
+    }
+
+    pub(crate) fn subscribe(
+        &self,
+        connect: Option<&str>,
+        disconnect: Option<&str>,
+        subscribe_topic: Option<&str>,
+        unsubscribe_topic: Option<&str>,
+    ) -> Result<()> {
+        let event_sender = match self.subscribe_evt_sender.as_ref() {
+            Some(event_sender) => event_sender,
+            None => bail!("There is no sender. Transporter may not have started."),
+        };
+        let event = SubscribeEvent {
+            connect: connect.map(|s| s.to_string()),
+            disconnect: disconnect.map(|s| s.to_string()),
+            subscribe: subscribe_topic.map(|s| s.to_string()),
+            unsubscribe: unsubscribe_topic.map(|s| s.to_string()),
+        };
+        if event_sender.send(event).is_err() {
+            bail!("The transporter is stopped");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn srv_disconnect(&self, address: &str) {
+        self.srv_connections.lock().unwrap().remove(address);
+    }
+
+    /// Publish a message. It returns false if it was dropped at the high water
+    /// mark, which libzmq doesn't report: its PUB socket skips the subscribers
+    /// at the mark and the send succeeds.
+    pub(crate) fn publish(&self, publish_message: PublishMessage) -> Result<bool> {
+        let messages = {
+            vec![
+                zmq::Message::from(&publish_message.topic),
+                zmq::Message::from(&self.publisher_address),
+                zmq::Message::from(&publish_message.data),
+                zmq::Message::from(&publish_message.msg_type),
+            ]
+        };
+        let mut v = VecDeque::from(messages);
+        while let Some(zmq_msg) = v.pop_front() {
+            let flag = if v.is_empty() { 0 } else { zmq::SNDMORE };
+            // Don't block at the high water mark of a subscriber.
+            match self.publisher.send(zmq_msg, flag | zmq::DONTWAIT) {
+                Err(zmq::Error::EAGAIN) => return Ok(false),
+                result => result?,
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Publish several messages. It returns how many of them failed.
+    pub(crate) fn publish_batch(&self, msgs: &[PublishMessage]) -> PublishFailures {
+        let publisher_address = self.publisher_address.as_bytes();
+        let mut failures = PublishFailures::default();
+        for msg in msgs {
+            let frames: [&[u8]; 4] = [
+                msg.topic.as_bytes(),
+                publisher_address,
+                &msg.data,
+                msg.msg_type.as_bytes(),
+            ];
+            match self.publisher.send_multipart(frames, zmq::DONTWAIT) {
+                Ok(_) => {}
+                Err(zmq::Error::EAGAIN) => failures.dropped += 1,
+                Err(err) => {
+                    debug!("Failed to publish: {}", err);
+                    failures.errors += 1;
+                }
+            }
+        }
+        failures
+    }
+
+    pub(crate) fn request(&mut self, msg: RequestMessage) -> Result<()> {
+        let address = match msg.replier_address {
+            Some(address) => address.to_string(),
+            None => bail!("RequestMessage.address is None"),
+        };
+
+        let new_connection = self.srv_connections.lock().unwrap().insert(address.clone());
+        if new_connection {
+            self.requester.lock().unwrap().connect(&address)?;
+            debug!("Connected to [{}] for service requests", address);
+        }
+
+        let frames = vec![
+            msg.replier_id.into_bytes(),
+            msg.topic.clone().into_bytes(),
+            self.requester_address.clone().into_bytes(),
+            self.requester_id.clone().into_bytes(),
+            msg.node_uuid.clone().into_bytes(),
+            msg.req_uuid.clone().into_bytes(),
+            msg.data,
+            msg.req_type.into_bytes(),
+            msg.res_type.into_bytes(),
+        ];
+        let result = self.requester.lock().unwrap().send_multipart(&frames, 0);
+        match result {
+            Err(zmq::Error::EHOSTUNREACH) if new_connection => {}
+            result => return Ok(result?),
+        }
+
+        // Retry on the runtime until the handshake of the new connection is
+        // done, and answer with a failed response if the replier can't be reached.
+        let failure = ReplyMessage {
+            requester_address: None,
+            requester_id: self.requester_id.clone(),
+            topic: msg.topic,
+            node_uuid: msg.node_uuid,
+            req_uuid: msg.req_uuid,
+            data: vec![],
+            result: false,
+        };
+        let requester = self.requester.clone();
+        let response_handler = self.response_handler.clone();
+        tokio::spawn(async move {
+            let deadline = Instant::now() + Duration::from_millis(CONNECT_TIMEOUT);
+            loop {
+                sleep(Duration::from_millis(SEND_RETRY_IVL)).await;
+                let result = requester.lock().unwrap().send_multipart(&frames, 0);
+                match result {
+                    Ok(_) => return,
+                    Err(zmq::Error::EHOSTUNREACH) if Instant::now() < deadline => {}
+                    Err(e) => {
+                        error!("failed requesting: {}", e);
+                        if let Some(handler) = response_handler.lock().unwrap().as_ref() {
+                            handler(failure);
+                        }
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    pub(crate) fn reply(&self, msg: ReplyMessage) -> Result<()> {
+        if let Some(sender) = self.reply_msg_sender.as_ref() {
+            sender.send(msg)?;
+            Ok(())
+        } else {
+            bail!("There is no sender. Transporter may not have started.");
+        }
+    }
+
+    pub(crate) fn set_subscription_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(PublishMessage) + Send + 'static,
+    {
+        self.subscription_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    /// libzmq doesn't report the messages its SUB socket drops at the high
+    /// water mark, so the handler is never called with this transport.
+    pub(crate) fn set_drop_handler<F>(&mut self, _handler: F)
+    where
+        F: Fn(&str) + Send + 'static,
+    {
+    }
+
+    pub(crate) fn set_request_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(RequestMessage) + Send + 'static,
+    {
+        self.request_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    pub(crate) fn set_response_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(ReplyMessage) + Send + 'static,
+    {
+        self.response_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    /// Spawn the receive task on the current tokio runtime.
+    pub(crate) fn start(&mut self) -> Result<()> {
+        let sockets = match self.receive_sockets.take() {
+            Some(sockets) => sockets,
+            None => bail!("The transporter was already started."),
+        };
+        let mut inner = TransporterInner::new(
+            sockets,
+            self.connections.clone(),
+            self.srv_connections.clone(),
+            self.subscription_handler.clone(),
+            self.request_handler.clone(),
+            self.response_handler.clone(),
+        )
+        .context("failed creating the receive sockets")?;
+
+        let (subscribe_evt_sender, subscribe_evt_receiver) =
+            mpsc::unbounded_channel::<SubscribeEvent>();
+        let (rep_msg_snd, reply_msg_receiver) = mpsc::unbounded_channel::<ReplyMessage>();
+        self.subscribe_evt_sender = Some(subscribe_evt_sender);
+        self.reply_msg_sender = Some(rep_msg_snd);
+
+        let handle = tokio::spawn(async move {
+            inner.run(subscribe_evt_receiver, reply_msg_receiver).await;
+            debug!("Transporter task finished.");
+        });
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    /// Stop the receive task. Its sockets are closed when the runtime drops it.
+    pub(crate) async fn stop(&mut self) {
+        self.subscribe_evt_sender = None;
+        self.reply_msg_sender = None;
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+        if let Some(mut zap_handler) = self.zap_handler.take() {
+            // Joining the ZAP thread waits for its poll timeout.
+            if let Err(err) = tokio::task::spawn_blocking(move || zap_handler.stop()).await {
+                error!("Failed to stop the ZAP handler: {}", err);
+            }
+        }
+    }
+}
+
+// Sockets bound at creation, so that security and bind errors are returned
+// before the receive task is started.
+struct ReceiveSockets {
+    subscriber: zmq::Socket,
+    response_receiver: zmq::Socket,
+    replier: zmq::Socket,
+}
+
+impl ReceiveSockets {
+    fn new(
+        context: &zmq::Context,
+        any_tcp: &str,
+        requester_id: &str,
+        replier_id: &str,
+        security: &Security,
+    ) -> Result<Self> {
+        let subscriber = context.socket(zmq::SUB)?;
+        let response_receiver = context.socket(zmq::ROUTER)?;
+        let replier = context.socket(zmq::ROUTER)?;
+
+        subscriber.set_rcvhwm(DEFAULT_RCV_HWM)?;
+        security
+            .apply_client(&subscriber)
+            .context("failed setting the subscriber security")?;
+
+        // The repliers connect to the response receiver to send the responses.
+        // A CURVE/PLAIN role doesn't depend on the side that connects, so the
+        // replier is the server of both of its connections.
+        response_receiver.set_identity(requester_id.as_bytes())?;
+        security
+            .apply_client(&response_receiver)
+            .context("failed setting the response receiver security")?;
+        response_receiver
+            .bind(any_tcp)
+            .context("failed binding response_receiver")?;
+
+        replier.set_identity(replier_id.as_bytes())?;
+        let linger_val = 0;
+        replier.set_linger(linger_val)?;
+        let route_on = true;
+        replier.set_router_mandatory(route_on)?;
+        security
+            .apply_server(&replier)
+            .context("failed setting the replier security")?;
+        replier.bind(any_tcp).context("failed binding replier")?;
+
+        Ok(ReceiveSockets {
+            subscriber,
+            response_receiver,
+            replier,
+        })
+    }
+
+    fn requester_address(&self) -> String {
+        match self.response_receiver.get_last_endpoint() {
+            Ok(Ok(endpoint)) => endpoint,
+            _ => "error".to_string(),
+        }
+    }
+
+    fn replier_address(&self) -> String {
+        match self.replier.get_last_endpoint() {
+            Ok(Ok(endpoint)) => endpoint,
+            _ => "".to_string(),
+        }
+    }
+}
+
+// The sockets are wrapped in `AsyncFd` to wait on their ZMQ_FD. The ZMQ_FD is
+// edge triggered, so `ZMQ_EVENTS` must be checked after every wakeup and after
+// sending on the same socket.
+struct TransporterInner {
+    subscriber: AsyncFd<zmq::Socket>,
+    response_receiver: AsyncFd<zmq::Socket>,
+    replier: AsyncFd<zmq::Socket>,
+
+    connections: Arc<Mutex<HashSet<String>>>,
+    srv_connections: Arc<Mutex<HashSet<String>>>,
+
+    subscription_handler: Arc<Mutex<Option<SubscriptionHandlerType>>>,
+    request_handler: Arc<Mutex<Option<RequestHandlerType>>>,
+    response_handler: Arc<Mutex<Option<ResponseHandlerType>>>,
+
+    // Replies waiting for the handshake of a new connection.
+    pending_replies: Vec<(ReplyMessage, Instant)>,
+}
+
+impl TransporterInner {
+    fn new(
+        sockets: ReceiveSockets,
+        connections: Arc<Mutex<HashSet<String>>>,
+        srv_connections: Arc<Mutex<HashSet<String>>>,
+        subscription_handler: Arc<Mutex<Option<SubscriptionHandlerType>>>,
+        request_handler: Arc<Mutex<Option<RequestHandlerType>>>,
+        response_handler: Arc<Mutex<Option<ResponseHandlerType>>>,
+    ) -> Result<Self> {
+        let ReceiveSockets {
+            subscriber,
+            response_receiver,
+            replier,
+        } = sockets;
+
+        Ok(TransporterInner {
+            subscriber: register(subscriber)?,
+            response_receiver: register(response_receiver)?,
+            replier: register(replier)?,
+            connections,
+            srv_connections,
+            subscription_handler,
+            request_handler,
+            response_handler,
+            pending_replies: Vec::new(),
+        })
+    }
+
+    async fn run(
+        &mut self,
+        mut subscribe_evt_receiver: UnboundedReceiver<SubscribeEvent>,
+        mut reply_msg_receiver: UnboundedReceiver<ReplyMessage>,
+    ) {
+        let retry_ivl = Duration::from_millis(SEND_RETRY_IVL);
+        loop {
+            if let Err(e) = self.dispatch_pending() {
+                error!("failed receiving: {}", e);
+            }
+
+            select! {
+                Some(event) = subscribe_evt_receiver.recv() => {
+                    self.on_subscribe_event(event);
+                }
+                Some(msg) = reply_msg_receiver.recv() => {
+                    if let Err(e) = self.reply(msg) {
+                        error!("failed replying: {}", e);
+                    }
+                }
+                _ = sleep(retry_ivl), if !self.pending_replies.is_empty() => {
+                    self.retry_replies();
+                }
+                guard = self.subscriber.readable_mut() => {
+                    guard.expect("waiting on the subscriber failed").clear_ready();
+                }
+                guard = self.replier.readable_mut() => {
+                    guard.expect("waiting on the replier failed").clear_ready();
+                }
+                guard = self.response_receiver.readable_mut() => {
+                    guard.expect("waiting on the response receiver failed").clear_ready();
+                }
+            }
+        }
+    }
+
+    // Handle every message already queued by libzmq.
+    fn dispatch_pending(&self) -> Result<()> {
+        while has_input(&self.subscriber)? {
+            self.on_subscribe()?;
+        }
+        while has_input(&self.replier)? {
+            self.on_request()?;
+        }
+        while has_input(&self.response_receiver)? {
+            self.on_response()?;
+        }
+        Ok(())
+    }
+
+    fn on_subscribe_event(&self, event: SubscribeEvent) {
+        if let Some(address) = event.connect {
+            if let Err(e) = self.connect(&address) {
+                error!("failed connecting subscriber to [{}]: {}", address, e);
+            }
+        }
+        if let Some(address) = event.disconnect {
+            if let Err(e) = self.disconnect(&address) {
+                error!("failed disconnecting subscriber from [{}]: {}", address, e);
+            }
+        }
+        if let Some(topic) = event.subscribe {
+            if let Err(e) = self.set_subscribe(&topic) {
+                error!("failed subscribing to [{}]: {}", topic, e);
+            }
+        }
+        if let Some(topic) = event.unsubscribe {
+            if let Err(e) = self.set_unsubscribe(&topic) {
+                error!("failed unsubscribing to [{}]: {}", topic, e);
+            }
+        }
+    }
+
+    fn connect(&self, address: &str) -> Result<()> {
+        let mut connections = self.connections.lock().unwrap();
+        if connections.insert(address.to_string()) {
+            self.subscriber.get_ref().connect(address)?;
+            debug!("Connected to [{}] for subscriptions", address);
+        } else {
+            debug!("Already connected");
+        }
+        Ok(())
+    }
+    fn disconnect(&self, address: &str) -> Result<()> {
+        self.connections.lock().unwrap().remove(address);
+        self.subscriber.get_ref().disconnect(address)?;
+        Ok(())
+    }
+
+    fn set_subscribe(&self, topic: &str) -> Result<()> {
+        self.subscriber.get_ref().set_subscribe(topic.as_bytes())?;
+        Ok(())
+    }
+
+    fn set_unsubscribe(&self, topic: &str) -> Result<()> {
+        self.subscriber
+            .get_ref()
+            .set_unsubscribe(topic.as_bytes())?;
+        Ok(())
+    }
+
+    fn reply(&mut self, msg: ReplyMessage) -> Result<()> {
+        let address = match msg.requester_address.as_ref() {
+            Some(address) => address.to_string(),
+            None => bail!("ReplyMessage.address is None"),
+        };
+
+        let new_connection = self.srv_connections.lock().unwrap().insert(address.clone());
+        if new_connection {
+            self.replier.get_ref().connect(&address)?;
+            debug!("Connected to [{}] for service response", address);
+        }
+
+        match self.send_reply(&msg) {
+            // Retried by the receive loop until the handshake is done.
+            Err(zmq::Error::EHOSTUNREACH) => {
+                let deadline = Instant::now() + Duration::from_millis(CONNECT_TIMEOUT);
+                self.pending_replies.push((msg, deadline));
+                Ok(())
+            }
+            result => Ok(result?),
+        }
+    }
+
+    fn retry_replies(&mut self) {
+        let now = Instant::now();
+        for (msg, deadline) in std::mem::take(&mut self.pending_replies) {
+            match self.send_reply(&msg) {
+                Ok(_) => {}
+                Err(zmq::Error::EHOSTUNREACH) if now < deadline => {
+                    self.pending_replies.push((msg, deadline));
+                }
+                Err(e) => error!("failed replying: {}", e),
+            }
+        }
+    }
+
+    fn send_reply(&self, msg: &ReplyMessage) -> zmq::Result<()> {
+        let result_str = if msg.result { "1" } else { "0" };
+        let messages = [
+            zmq::Message::from(&msg.requester_id),
+            zmq::Message::from(&msg.topic),
+            zmq::Message::from(&msg.node_uuid),
+            zmq::Message::from(&msg.req_uuid),
+            zmq::Message::from(&msg.data),
+            zmq::Message::from(result_str),
+        ];
+
+        let mut v = VecDeque::from(messages);
+        while let Some(zmq_msg) = v.pop_front() {
+            let flag = if v.is_empty() { 0 } else { zmq::SNDMORE };
+            self.replier.get_ref().send(zmq_msg, flag)?;
+        }
+        Ok(())
+    }
+
+    fn on_subscribe(&self) -> Result<()> {
+        let subscriber = self.subscriber.get_ref();
+        let topic = subscriber.recv_msg(0)?;
+        let address = subscriber.recv_msg(0)?;
+        let data = subscriber.recv_msg(0)?;
+        let msg_type = subscriber.recv_msg(0)?;
+
+        if let Some(handler) = self.subscription_handler.lock().unwrap().as_ref() {
+            handler(PublishMessage {
+                topic: topic.as_str().unwrap_or("").to_string(),
+                data: data.to_vec(),
+                msg_type: msg_type.as_str().unwrap_or("").to_string(),
+                publisher_address: address.as_str().unwrap_or("").to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn on_request(&self) -> Result<()> {
+        let replier = self.replier.get_ref();
+        let replier_id = replier.recv_msg(0)?;
+        let topic = replier.recv_msg(0)?;
+        let requester_address = replier.recv_msg(0)?;
+        let requester_id = replier.recv_msg(0)?;
+        let node_uuid = replier.recv_msg(0)?;
+        let req_uuid = replier.recv_msg(0)?;
+        let data = replier.recv_msg(0)?;
+        let req_type = replier.recv_msg(0)?;
+        let res_type = replier.recv_msg(0)?;
+
+        if let Some(handler) = self.request_handler.lock().unwrap().as_ref() {
+            handler(RequestMessage {
+                replier_address: None,
+                replier_id: replier_id.as_str().unwrap_or("").to_string(),
+                topic: topic.as_str().unwrap_or("").to_string(),
+                requester_address: requester_address.as_str().unwrap_or("").to_string(),
+                requester_id: requester_id.as_str().unwrap_or("").to_string(),
+                node_uuid: node_uuid.as_str().unwrap_or("").to_string(),
+                req_uuid: req_uuid.as_str().unwrap_or("").to_string(),
+                data: data.to_vec(),
+                req_type: req_type.as_str().unwrap_or("").to_string(),
+                res_type: res_type.as_str().unwrap_or("").to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn on_response(&self) -> Result<()> {
+        let response_receiver = self.response_receiver.get_ref();
+        let requester_id = response_receiver.recv_msg(0)?;
+        let topic = response_receiver.recv_msg(0)?;
+        let node_uuid = response_receiver.recv_msg(0)?;
+        let req_uuid = response_receiver.recv_msg(0)?;
+        let data = response_receiver.recv_msg(0)?;
+        let result_str = response_receiver.recv_msg(0)?;
+
+        if let Some(handler) = self.response_handler.lock().unwrap().as_ref() {
+            handler(ReplyMessage {
+                requester_address: None,
+                requester_id: requester_id.as_str().unwrap_or("").to_string(),
+                topic: topic.as_str().unwrap_or("").to_string(),
+                node_uuid: node_uuid.as_str().unwrap_or("").to_string(),
+                req_uuid: req_uuid.as_str().unwrap_or("").to_string(),
+                data: data.to_vec(),
+                result: result_str.as_str().unwrap_or("0") == "1",
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// Wait on the ZMQ_FD of a socket in the runtime.
+fn register(socket: zmq::Socket) -> io::Result<AsyncFd<zmq::Socket>> {
+    // SAFETY: the ZMQ_FD is owned by the socket, it stays open and the same
+    // until the socket is closed, which only happens when the `AsyncFd` drops it.
+    unsafe { AsyncFd::register(socket) }.map_err(io::Error::from)
+}
+
+fn has_input(socket: &AsyncFd<zmq::Socket>) -> Result<bool> {
+    Ok(socket.get_ref().get_events()?.contains(zmq::POLLIN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{CurveKeys, PlainCredentials};
+    use prost::Message;
+    use std::io::Cursor;
+    use std::sync::mpsc as std_mpsc;
+    use std::thread;
+    use std::time::SystemTime;
+
+    const IP: &str = "127.0.0.1";
+    const TOPIC: &str = "topic";
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Person {
+        #[prost(string, tag = "1")]
+        pub name: ::prost::alloc::string::String,
+        #[prost(int32, tag = "2")]
+        pub id: i32,
+    }
+
+    #[tokio::test]
+    async fn test_pub_sub() {
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .try_init();
+
+        let mut transporter1 = Transporter::new(IP).unwrap();
+        let mut transporter2 = Transporter::new(IP).unwrap();
+        transporter1.start().unwrap();
+        let my_address = transporter1.publisher_address();
+        let address = my_address.clone();
+
+        let check = Arc::new(Mutex::new(None));
+        let check2 = check.clone();
+
+        let now = SystemTime::now();
+        let now_clone = now.clone();
+
+        tokio::spawn(async move {
+            transporter2.start().unwrap();
+            transporter2
+                .subscribe(Some(&address), None, Some(TOPIC), None)
+                .unwrap();
+            transporter2.set_subscription_handler(move |msg| {
+                if let Ok(person) = Person::decode(&mut Cursor::new(msg.data)) {
+                    check2.lock().unwrap().replace(person);
+
+                    println!("elapsed: {:?}", now_clone.elapsed().unwrap());
+                }
+            });
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        });
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let person = Person {
+            name: "Alice".to_string(),
+            id: 1234,
+        };
+
+        let mut data = person.encode_to_vec();
+        transporter1
+            .publish(PublishMessage {
+                topic: TOPIC.to_string(),
+                publisher_address: my_address.clone(),
+                msg_type: "Person".to_string(),
+                data,
+            })
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        {
+            let person = check.lock().unwrap().clone().unwrap();
+            assert_eq!(person.name, "Alice");
+            assert_eq!(person.id, 1234);
+        }
+    }
+    // Subscriptions are applied without waiting for a poll timeout.
+    #[tokio::test]
+    async fn test_subscribe_latency() {
+        let mut publisher = Transporter::new(IP).unwrap();
+        publisher.start().unwrap();
+        let address = publisher.publisher_address();
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subscriber = Transporter::new(IP).unwrap();
+        subscriber.start().unwrap();
+        subscriber.set_subscription_handler(move |msg| {
+            let _ = tx.send(msg.data);
+        });
+
+        let now = SystemTime::now();
+        subscriber
+            .subscribe(Some(&address), None, Some(TOPIC), None)
+            .unwrap();
+        let received = loop {
+            publisher
+                .publish(PublishMessage {
+                    topic: TOPIC.to_string(),
+                    publisher_address: address.clone(),
+                    msg_type: "Person".to_string(),
+                    data: vec![1, 2, 3],
+                })
+                .unwrap();
+            let timeout = Duration::from_millis(5);
+            if let Ok(received) = tokio::time::timeout(timeout, rx.recv()).await {
+                break received;
+            }
+        };
+        assert_eq!(received, Some(vec![1, 2, 3]));
+        assert!(now.elapsed().unwrap() < Duration::from_millis(200));
+
+        subscriber.stop().await;
+        publisher.stop().await;
+    }
+    #[tokio::test]
+    async fn test_pub_sub_plain_auth() {
+        let server = Security {
+            plain: Some(PlainCredentials::new("user", "pass")),
+            curve: None,
+        };
+        let client = server.clone();
+        let intruder = Security {
+            plain: Some(PlainCredentials::new("user", "wrong")),
+            curve: None,
+        };
+
+        let mut publisher = Transporter::with_security(IP, server).unwrap();
+        publisher.start().unwrap();
+        let address = publisher.publisher_address();
+
+        let mut received = vec![];
+        for security in [client, intruder] {
+            let check = Arc::new(Mutex::new(None));
+            let check2 = check.clone();
+            let mut subscriber = Transporter::with_security(IP, security).unwrap();
+            subscriber.start().unwrap();
+            subscriber.set_subscription_handler(move |msg| {
+                check2.lock().unwrap().replace(msg.data);
+            });
+            subscriber
+                .subscribe(Some(&address), None, Some(TOPIC), None)
+                .unwrap();
+            received.push((subscriber, check));
+        }
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        publisher
+            .publish(PublishMessage {
+                topic: TOPIC.to_string(),
+                publisher_address: address.clone(),
+                msg_type: "Person".to_string(),
+                data: vec![1, 2, 3],
+            })
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(*received[0].1.lock().unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(*received[1].1.lock().unwrap(), None);
+
+        for (mut subscriber, _) in received {
+            subscriber.stop().await;
+        }
+        publisher.stop().await;
+    }
+    #[tokio::test]
+    async fn test_curve() {
+        if zmq::has("curve") != Some(true) {
+            return;
+        }
+        let server_keys = zmq::CurveKeyPair::new().unwrap();
+        let client_keys = zmq::CurveKeyPair::new().unwrap();
+        let curve_security = |keys: &zmq::CurveKeyPair, server_key: &[u8]| Security {
+            plain: None,
+            curve: Some(CurveKeys {
+                public_key: keys.public_key.to_vec(),
+                secret_key: keys.secret_key.to_vec(),
+                server_key: server_key.to_vec(),
+            }),
+        };
+        let server = curve_security(&server_keys, &server_keys.public_key);
+        let client = curve_security(&client_keys, &server_keys.public_key);
+        let intruder = curve_security(&client_keys, &client_keys.public_key);
+
+        let mut publisher = Transporter::with_security(IP, server).unwrap();
+        publisher.start().unwrap();
+        let address = publisher.publisher_address();
+        let replier_address = publisher.replier_address();
+        let replier_id = publisher.replier_id();
+        publisher.set_request_handler(|_| {});
+
+        let mut received = vec![];
+        for security in [client, intruder] {
+            let (tx, rx) = std_mpsc::channel();
+            let mut subscriber = Transporter::with_security(IP, security).unwrap();
+            subscriber.start().unwrap();
+            subscriber.set_subscription_handler(move |msg| {
+                tx.send(msg.data).unwrap();
+            });
+            subscriber
+                .subscribe(Some(&address), None, Some(TOPIC), None)
+                .unwrap();
+            received.push((subscriber, rx));
+        }
+
+        // Round trip of a service request, the response goes through a second
+        // CURVE connection from the replier to the requester.
+        let (req_tx, req_rx) = std_mpsc::channel();
+        publisher.set_request_handler(move |msg| {
+            req_tx.send(msg).unwrap();
+        });
+        let (res_tx, res_rx) = std_mpsc::channel();
+        let requester = &mut received[0].0;
+        requester.set_response_handler(move |msg| {
+            res_tx.send(msg.data).unwrap();
+        });
+        requester
+            .request(RequestMessage {
+                replier_address: Some(replier_address),
+                replier_id,
+                topic: TOPIC.to_string(),
+                requester_address: "unset".to_string(),
+                requester_id: "unset".to_string(),
+                node_uuid: "node_uuid".to_string(),
+                req_uuid: "req_uuid".to_string(),
+                data: vec![4, 5, 6],
+                req_type: "Person".to_string(),
+                res_type: "Person".to_string(),
+            })
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        publisher
+            .publish(PublishMessage {
+                topic: TOPIC.to_string(),
+                publisher_address: address.clone(),
+                msg_type: "Person".to_string(),
+                data: vec![1, 2, 3],
+            })
+            .unwrap();
+
+        let request = req_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(request.data, vec![4, 5, 6]);
+        publisher
+            .reply(ReplyMessage {
+                requester_address: Some(request.requester_address),
+                requester_id: request.requester_id,
+                topic: request.topic,
+                node_uuid: request.node_uuid,
+                req_uuid: request.req_uuid,
+                data: vec![7, 8, 9],
+                result: true,
+            })
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(res_rx.try_recv(), Ok(vec![7, 8, 9]));
+        assert_eq!(received[0].1.try_recv(), Ok(vec![1, 2, 3]));
+        assert!(received[1].1.try_recv().is_err());
+
+        for (mut subscriber, _) in received {
+            subscriber.stop().await;
+        }
+        publisher.stop().await;
+    }
+    #[tokio::test]
+    async fn test_req_res() {
+        // tracing_subscriber::fmt()
+        //     .with_max_level(tracing::Level::DEBUG)
+        //     .init();
+        let mut transporter1 = Transporter::new(IP).unwrap();
+        let mut transporter2 = Transporter::new(IP).unwrap();
+        let replier_address = transporter2.replier_address.clone();
+        let replier_id = transporter2.replier_id.clone();
+
+        let now = SystemTime::now();
+        let now_clone = now.clone();
+
+        // The receive task runs on the test runtime.
+        transporter2.start().unwrap();
+        thread::spawn(move || {
+            let (tx, rx) = std_mpsc::channel::<ReplyMessage>();
+
+            transporter2.set_request_handler(move |msg| {
+                if let Ok(mut person) = Person::decode(&mut Cursor::new(msg.data)) {
+                    person.name = "Bob".to_string();
+                    tx.send(ReplyMessage {
+                        requester_address: Some(msg.requester_address),
+                        requester_id: msg.requester_id,
+                        topic: msg.topic,
+                        node_uuid: msg.node_uuid,
+                        req_uuid: msg.req_uuid,
+                        data: person.encode_to_vec(),
+                        result: true,
+                    })
+                    .unwrap();
+                }
+            });
+
+            if let Ok(msg) = rx.recv() {
+                transporter2.reply(msg).unwrap();
+                println!("req elapsed: {:?}", now_clone.elapsed().unwrap());
+            }
+            // sleep(Duration::from_millis(1000));
+        });
+
+        transporter1.start().unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let check = Arc::new(Mutex::new(None));
+        let check2 = check.clone();
+
+        transporter1.set_response_handler(move |msg| {
+            println!("res elapsed: {:?}", now.elapsed().unwrap());
+
+            if let Ok(person) = Person::decode(&mut Cursor::new(msg.data)) {
+                check2.lock().unwrap().replace(person);
+            }
+        });
+
+        let person = Person {
+            name: "Alice".to_string(),
+            id: 1234,
+        };
+
+        let mut data = person.encode_to_vec();
+        if let Err(e) = transporter1.request(RequestMessage {
+            replier_address: Some(replier_address),
+            replier_id,
+            topic: TOPIC.to_string(),
+            requester_address: "unset".to_string(),
+            requester_id: "unset".to_string(),
+            node_uuid: "node_uuid".to_string(),
+            req_uuid: "req_uuid".to_string(),
+            data,
+            req_type: "Person".to_string(),
+            res_type: "Person".to_string(),
+        }) {
+            println!("request failed: {}", e);
+        }
+
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+
+        {
+            let person = check.lock().unwrap();
+            // info!("person: {:?}", person);
+            assert!(person.is_some());
+            let person = person.as_ref().unwrap();
+            assert_eq!(person.id, 1234);
+            assert_eq!(person.name, "Bob");
+        }
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(not(any(feature = "zmq", feature = "zmtp")))]
+compile_error!("Either the `zmq` or the `zmtp` feature must be enabled.");
+
+#[cfg(not(feature = "zmtp"))]
+mod libzmq;
+#[cfg(feature = "zmtp")]
+mod zmtp;
+
+#[cfg(not(feature = "zmtp"))]
+pub(crate) use libzmq::Transporter;
+#[cfg(feature = "zmtp")]
+pub(crate) use zmtp::Transporter;
+
+/// The high water mark of the receive message buffer.
+pub(crate) const DEFAULT_RCV_HWM: i32 = 1000;
+
+/// The high water mark of the send message buffer.
+pub(crate) const DEFAULT_SND_HWM: i32 = 1000;
+
+#[derive(Debug, Clone)]
+pub(crate) struct PublishMessage {
+    pub topic: String,
+    pub publisher_address: String,
+    pub msg_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Messages of a batch that could not be published.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct PublishFailures {
+    /// Dropped at the high water mark of a subscriber.
+    pub dropped: usize,
+    /// Failed with a send error.
+    pub errors: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RequestMessage {
+    pub replier_address: Option<String>,
+    pub replier_id: String,
+    pub topic: String,
+    pub requester_address: String,
+    pub requester_id: String,
+    pub node_uuid: String,
+    pub req_uuid: String,
+    pub data: Vec<u8>,
+    pub req_type: String,
+    pub res_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ReplyMessage {
+    pub requester_address: Option<String>,
+    pub requester_id: String,
+    pub topic: String,
+    pub node_uuid: String,
+    pub req_uuid: String,
+    pub data: Vec<u8>,
+    pub result: bool, // True when the service request was successful or false otherwise.
+}
+
+pub(crate) struct SubscribeEvent {
+    pub connect: Option<String>,
+    pub disconnect: Option<String>,
+    pub subscribe: Option<String>,
+    pub unsubscribe: Option<String>,
+}
+
+pub(crate) type SubscriptionHandlerType = Box<dyn Fn(PublishMessage) + Send>;
+pub(crate) type RequestHandlerType = Box<dyn Fn(RequestMessage) + Send>;
+pub(crate) type ResponseHandlerType = Box<dyn Fn(ReplyMessage) + Send>;
+/// Called with the topic of a received message dropped at the high water mark.
+#[cfg(feature = "zmtp")]
+pub(crate) type DropHandlerType = Box<dyn Fn(&str) + Send>;
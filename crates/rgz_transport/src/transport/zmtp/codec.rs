@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::security::PlainCredentials;
+
+const FLAG_MORE: u8 = 0x01;
+const FLAG_LONG: u8 = 0x02;
+const FLAG_COMMAND: u8 = 0x04;
+
+const GREETING_LEN: usize = 64;
+
+/// Largest frame accepted from a peer.
+const MAX_FRAME_SIZE: u64 = 256 << 20;
+
+pub(super) type Metadata = HashMap<String, Vec<u8>>;
+
+/// Security mechanism used during the handshake (ZMQ RFC 23 and 24).
+#[derive(Debug, Clone)]
+pub(super) enum Mechanism {
+    Null,
+    PlainClient(PlainCredentials),
+    PlainServer(PlainCredentials),
+}
+
+impl Mechanism {
+    fn name(&self) -> &'static str {
+        match self {
+            Mechanism::Null => "NULL",
+            _ => "PLAIN",
+        }
+    }
+    fn as_server(&self) -> bool {
+        matches!(self, Mechanism::PlainServer(_))
+    }
+}
+
+/// Something received from a peer after the handshake.
+#[derive(Debug, PartialEq)]
+pub(super) enum Incoming {
+    Message(Vec<Vec<u8>>),
+    Command(String, Vec<u8>),
+}
+
+/// Encode a multipart message.
+pub(super) fn encode_message<T: AsRef<[u8]>>(frames: &[T]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for (i, frame) in frames.iter().enumerate() {
+        let flags = if i + 1 < frames.len() { FLAG_MORE } else { 0 };
+        encode_frame(&mut buf, flags, frame.as_ref());
+    }
+    buf
+}
+
+/// Encode a command such as READY or SUBSCRIBE.
+pub(super) fn encode_command(name: &str, data: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(1 + name.len() + data.len());
+    body.push(name.len() as u8);
+    body.extend_from_slice(name.as_bytes());
+    body.extend_from_slice(data);
+    let mut buf = Vec::new();
+    encode_frame(&mut buf, FLAG_COMMAND, &body);
+    buf
+}
+
+fn encode_frame(buf: &mut Vec<u8>, flags: u8, body: &[u8]) {
+    if body.len() > u8::MAX as usize {
+        buf.push(flags | FLAG_LONG);
+        buf.extend_from_slice(&(body.len() as u64).to_be_bytes());
+    } else {
+        buf.push(flags);
+        buf.push(body.len() as u8);
+    }
+    buf.extend_from_slice(body);
+}
+
+/// Read a whole message or a command.
+pub(super) async fn read_incoming<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Incoming> {
+    let mut frames = Vec::new();
+    loop {
+        let flags = reader.read_u8().await?;
+        let size = if flags & FLAG_LONG != 0 {
+            reader.read_u64().await?
+        } else {
+            reader.read_u8().await? as u64
+        };
+        if size > MAX_FRAME_SIZE {
+            bail!("Frame too large [{}]", size);
+        }
+        // Grown as the bytes arrive, the announced size is not trusted.
+        let mut body = Vec::new();
+        (&mut *reader).take(size).read_to_end(&mut body).await?;
+        if body.len() as u64 != size {
+            bail!("Frame truncated [{}/{}]", body.len(), size);
+        }
+
+        if flags & FLAG_COMMAND != 0 {
+            if !frames.is_empty() {
+                bail!("Command inside a multipart message");
+            }
+            let (name, data) = parse_command(&body)?;
+            return Ok(Incoming::Command(name, data.to_vec()));
+        }
+        frames.push(body);
+        if flags & FLAG_MORE == 0 {
+            return Ok(Incoming::Message(frames));
+        }
+    }
+}
+
+fn parse_command(body: &[u8]) -> Result<(String, &[u8])> {
+    let name_len = match body.first() {
+        Some(len) => *len as usize,
+        None => bail!("Empty command"),
+    };
+    if body.len() < 1 + name_len {
+        bail!("Malformed command");
+    }
+    let name = String::from_utf8_lossy(&body[1..1 + name_len]).to_string();
+    Ok((name, &body[1 + name_len..]))
+}
+
+fn encode_metadata(properties: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for (name, value) in properties {
+        buf.push(name.len() as u8);
+        buf.extend_from_slice(name.as_bytes());
+        buf.extend_from_slice(&(value.len() as u32).to_be_bytes());
+        buf.extend_from_slice(value);
+    }
+    buf
+}
+
+fn parse_metadata(mut data: &[u8]) -> Result<Metadata> {
+    let mut metadata = Metadata::new();
+    while !data.is_empty() {
+        let name_len = data[0] as usize;
+        if data.len() < 1 + name_len + 4 {
+            bail!("Malformed metadata");
+        }
+        let name = String::from_utf8_lossy(&data[1..1 + name_len]).to_string();
+        data = &data[1 + name_len..];
+        let value_len = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+        if data.len() < 4 + value_len {
+            bail!("Malformed metadata");
+        }
+        metadata.insert(name, data[4..4 + value_len].to_vec());
+        data = &data[4 + value_len..];
+    }
+    Ok(metadata)
+}
+
+fn greeting(mechanism: &Mechanism) -> [u8; GREETING_LEN] {
+    let mut buf = [0u8; GREETING_LEN];
+    buf[0] = 0xFF;
+    buf[9] = 0x7F;
+    // ZMTP 3.0, so libzmq peers send subscriptions as messages.
+    buf[10] = 3;
+    buf[11] = 0;
+    let name = mechanism.name().as_bytes();
+    buf[12..12 + name.len()].copy_from_slice(name);
+    buf[32] = mechanism.as_server() as u8;
+    buf
+}
+
+async fn read_command<S: AsyncRead + Unpin>(stream: &mut S, expected: &str) -> Result<Vec<u8>> {
+    match read_incoming(stream).await? {
+        Incoming::Command(name, data) if name == expected => Ok(data),
+        Incoming::Command(name, data) if name == "ERROR" => {
+            let reason = data.get(1..).unwrap_or_default();
+            bail!(
+                "Peer refused the handshake: {}",
+                String::from_utf8_lossy(reason)
+            )
+        }
+        other => bail!("Expected {} but received {:?}", expected, other),
+    }
+}
+
+/// Exchange greetings and run the security handshake.
+/// It returns the metadata announced by the peer.
+pub(super) async fn handshake<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    mechanism: &Mechanism,
+    socket_type: &str,
+    identity: &[u8],
+) -> Result<Metadata> {
+    stream.write_all(&greeting(mechanism)).await?;
+    let mut peer = [0u8; GREETING_LEN];
+    stream.read_exact(&mut peer).await?;
+    if peer[0] != 0xFF || peer[9] != 0x7F || peer[10] < 3 {
+        bail!("Unsupported ZMTP peer");
+    }
+    let peer_mechanism = String::from_utf8_lossy(&peer[12..32]);
+    if peer_mechanism.trim_end_matches('\0') != mechanism.name() {
+        bail!("Security mechanism mismatch [{}]", peer_mechanism);
+    }
+
+    let properties = encode_metadata(&[
+        ("Socket-Type", socket_type.as_bytes()),
+        ("Identity", identity),
+    ]);
+    let metadata = match mechanism {
+        Mechanism::Null => {
+            stream
+                .write_all(&encode_command("READY", &properties))
+                .await?;
+            parse_metadata(&read_command(stream, "READY").await?)?
+        }
+        Mechanism::PlainClient(credentials) => {
+            let mut hello = vec![credentials.username.len() as u8];
+            hello.extend_from_slice(credentials.username.as_bytes());
+            hello.push(credentials.password.len() as u8);
+            hello.extend_from_slice(credentials.password.as_bytes());
+            stream.write_all(&encode_command("HELLO", &hello)).await?;
+            read_command(stream, "WELCOME").await?;
+            stream
+                .write_all(&encode_command("INITIATE", &properties))
+                .await?;
+            parse_metadata(&read_command(stream, "READY").await?)?
+        }
+        Mechanism::PlainServer(credentials) => {
+            let hello = read_command(stream, "HELLO").await?;
+            if !check_hello(&hello, credentials) {
+                let reason = "Invalid username or password";
+                let mut error = vec![reason.len() as u8];
+                error.extend_from_slice(reason.as_bytes());
+                stream.write_all(&encode_command("ERROR", &error)).await?;
+                bail!("{}", reason);
+            }
+            stream.write_all(&encode_command("WELCOME", &[])).await?;
+            let metadata = parse_metadata(&read_command(stream, "INITIATE").await?)?;
+            stream
+                .write_all(&encode_command("READY", &properties))
+                .await?;
+            metadata
+        }
+    };
+    Ok(metadata)
+}
+
+fn check_hello(hello: &[u8], credentials: &PlainCredentials) -> bool {
+    let username_len = match hello.first() {
+        Some(len) => *len as usize,
+        None => return false,
+    };
+    let username = match hello.get(1..1 + username_len) {
+        Some(username) => username,
+        None => return false,
+    };
+    let password = match hello.get(1 + username_len..) {
+        Some([len, password @ ..]) if *len as usize == password.len() => password,
+        _ => return false,
+    };
+    username == credentials.username.as_bytes() && password == credentials.password.as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_encode_decode() {
+        let long = vec![7u8; 300];
+        let buf = encode_message(&[b"topic".to_vec(), long.clone(), vec![]]);
+        assert_eq!(&buf[..7], &[FLAG_MORE, 5, b't', b'o', b'p', b'i', b'c']);
+        assert_eq!(buf[7], FLAG_MORE | FLAG_LONG);
+
+        let mut reader = buf.as_slice();
+        let incoming = read_incoming(&mut reader).await.unwrap();
+        assert_eq!(
+            incoming,
+            Incoming::Message(vec![b"topic".to_vec(), long, vec![]])
+        );
+
+        let buf = encode_command("SUBSCRIBE", b"topic");
+        let mut reader = buf.as_slice();
+        let incoming = read_incoming(&mut reader).await.unwrap();
+        assert_eq!(
+            incoming,
+            Incoming::Command("SUBSCRIBE".to_string(), b"topic".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bad_frame_size() {
+        // A size above the limit, without a body.
+        let mut buf = vec![FLAG_LONG];
+        buf.extend_from_slice(&(MAX_FRAME_SIZE + 1).to_be_bytes());
+        let mut reader = buf.as_slice();
+        assert!(read_incoming(&mut reader).await.is_err());
+
+        // A size larger than the bytes sent.
+        let mut buf = vec![FLAG_LONG];
+        buf.extend_from_slice(&MAX_FRAME_SIZE.to_be_bytes());
+        buf.extend_from_slice(b"body");
+        let mut reader = buf.as_slice();
+        assert!(read_incoming(&mut reader).await.is_err());
+    }
+
+    #[test]
+    fn test_metadata() {
+        let buf = encode_metadata(&[("Socket-Type", b"ROUTER"), ("Identity", b"")]);
+        let metadata = parse_metadata(&buf).unwrap();
+        assert_eq!(metadata["Socket-Type"], b"ROUTER");
+        assert_eq!(metadata["Identity"], b"");
+        assert!(parse_metadata(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handshake() {
+        let credentials = PlainCredentials::new("user", "pass");
+        for (client, server, ok) in [
+            (Mechanism::Null, Mechanism::Null, true),
+            (
+                Mechanism::PlainClient(credentials.clone()),
+                Mechanism::PlainServer(credentials.clone()),
+                true,
+            ),
+            (
+                Mechanism::PlainClient(PlainCredentials::new("user", "wrong")),
+                Mechanism::PlainServer(credentials.clone()),
+                false,
+            ),
+            (
+                Mechanism::Null,
+                Mechanism::PlainServer(credentials.clone()),
+                false,
+            ),
+        ] {
+            let (mut a, mut b) = tokio::io::duplex(1024);
+            let (client, server) = tokio::join!(
+                handshake(&mut a, &client, "SUB", b""),
+                handshake(&mut b, &server, "PUB", b"id"),
+            );
+            assert_eq!(server.is_ok(), ok);
+            if ok {
+                assert_eq!(client.unwrap()["Identity"], b"id");
+                assert_eq!(server.unwrap()["Socket-Type"], b"SUB");
+            }
+        }
+    }
+}
@@ -0,0 +1,916 @@
+//! Pure-Rust transport speaking ZMTP 3.0, wire compatible with the libzmq
+//! sockets used by gz-transport.
+
+mod codec;
+mod socket;
+
+use std::collections::HashSet;
+use std::net::TcpListener as StdTcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::net::TcpListener;
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::select;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::sync::oneshot;
+use tracing::{debug, error};
+
+use crate::security::Security;
+use crate::transport::{
+    DropHandlerType, PublishFailures, PublishMessage, ReplyMessage, RequestHandlerType,
+    RequestMessage, ResponseHandlerType, SubscribeEvent, SubscriptionHandlerType, DEFAULT_RCV_HWM,
+};
+use codec::Mechanism;
+use socket::{Frames, PubSocket, RouterSocket, SubSocket};
+
+/// Maximum time to wait for a new service connection (ms.).
+const CONNECT_TIMEOUT: u64 = 1000;
+
+pub(crate) struct Transporter {
+    host_addr: String,
+    security: Security,
+    publisher: Arc<PubSocket>,
+    publisher_listener: Option<StdTcpListener>,
+    requester: Option<RouterSocket>,
+
+    publisher_address: String,
+
+    requester_id: String,
+    requester_address: Arc<Mutex<String>>,
+
+    replier_id: String,
+    replier_address: Arc<Mutex<String>>,
+
+    connections: Arc<Mutex<HashSet<String>>>,
+    srv_connections: Arc<Mutex<HashSet<String>>>,
+
+    subscription_handler: Arc<Mutex<Option<SubscriptionHandlerType>>>,
+    request_handler: Arc<Mutex<Option<RequestHandlerType>>>,
+    response_handler: Arc<Mutex<Option<ResponseHandlerType>>>,
+    drop_handler: Arc<Mutex<Option<DropHandlerType>>>,
+
+    subscribe_evt_sender: Option<UnboundedSender<SubscribeEvent>>,
+    reply_msg_sender: Option<UnboundedSender<ReplyMessage>>,
+
+    // Runtime thread state.
+    runtime: Option<Handle>,
+    shutdown_sender: Option<oneshot::Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+fn bind_any(host_addr: &str) -> Result<StdTcpListener> {
+    let listener = StdTcpListener::bind((host_addr, 0))
+        .with_context(|| format!("failed binding socket [{}]", host_addr))?;
+    listener
+        .set_nonblocking(true)
+        .context("failed setting the socket non-blocking")?;
+    Ok(listener)
+}
+
+// The runtime of the transport thread, with the listeners registered to it.
+fn transport_runtime(listeners: [StdTcpListener; 3]) -> Result<(Runtime, [TcpListener; 3])> {
+    let runtime = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("failed building the transport runtime")?;
+    let listeners = {
+        let _guard = runtime.enter();
+        let [publisher, response, replier] = listeners;
+        [
+            TcpListener::from_std(publisher)?,
+            TcpListener::from_std(response)?,
+            TcpListener::from_std(replier)?,
+        ]
+    };
+    Ok((runtime, listeners))
+}
+
+fn endpoint(listener: &StdTcpListener) -> String {
+    match listener.local_addr() {
+        Ok(addr) => format!("tcp://{}", addr),
+        Err(_) => "".to_string(),
+    }
+}
+
+impl Transporter {
+    #[cfg(test)]
+    pub(crate) fn new(host_addr: &str) -> Result<Self> {
+        Self::with_security(host_addr, Security::default())
+    }
+
+    pub(crate) fn with_security(host_addr: &str, security: Security) -> Result<Self> {
+        if security.curve.is_some() {
+            bail!("CURVE is not supported by the zmtp transport");
+        }
+        let publisher_listener = bind_any(host_addr)?;
+        let publisher_address = endpoint(&publisher_listener);
+
+        Ok(Transporter {
+            host_addr: host_addr.to_string(),
+            security,
+            publisher: Arc::new(PubSocket::new()),
+            publisher_listener: Some(publisher_listener),
+            requester: None,
+            publisher_address,
+            requester_id: uuid::Uuid::new_v4().to_string(),
+            requester_address: Arc::new(Mutex::new("unset".to_string())),
+            replier_id: uuid::Uuid::new_v4().to_string(),
+            replier_address: Arc::new(Mutex::new("unset".to_string())),
+            connections: Arc::new(Mutex::new(HashSet::new())),
+            srv_connections: Arc::new(Mutex::new(HashSet::new())),
+            subscription_handler: Arc::new(Mutex::new(None)),
+            request_handler: Arc::new(Mutex::new(None)),
+            response_handler: Arc::new(Mutex::new(None)),
+            drop_handler: Arc::new(Mutex::new(None)),
+            subscribe_evt_sender: None,
+            reply_msg_sender: None,
+            runtime: None,
+            shutdown_sender: None,
+            handle: None,
+        })
+    }
+
+    pub(crate) fn publisher_address(&self) -> String {
+        self.publisher_address.clone()
+    }
+
+    pub(crate) fn bind_ipc(&self, endpoint: &str) -> Result<()> {
+        bail!("IPC is not supported by the zmtp transport [{}]", endpoint)
+    }
+
+    pub(crate) fn replier_address(&self) -> String {
+        self.replier_address.lock().unwrap().clone()
+    }
+    pub(crate) fn replier_id(&self) -> String {
+        self.replier_id.clone()
+    }
+
+    pub(crate) fn subscribe(
+        &self,
+        connect: Option<&str>,
+        disconnect: Option<&str>,
+        subscribe_topic: Option<&str>,
+        unsubscribe_topic: Option<&str>,
+    ) -> Result<()> {
+        let event_sender = match self.subscribe_evt_sender.as_ref() {
+            Some(event_sender) => event_sender,
+            None => bail!("There is no sender. Transporter may not have started."),
+        };
+        let event = SubscribeEvent {
+            connect: connect.map(|s| s.to_string()),
+            disconnect: disconnect.map(|s| s.to_string()),
+            subscribe: subscribe_topic.map(|s| s.to_string()),
+            unsubscribe: unsubscribe_topic.map(|s| s.to_string()),
+        };
+        if event_sender.send(event).is_err() {
+            bail!("The transporter is stopped");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn srv_disconnect(&self, address: &str) {
+        self.srv_connections.lock().unwrap().remove(address);
+    }
+
+    /// Publish a message. It returns false if a subscriber dropped it at the
+    /// high water mark.
+    pub(crate) fn publish(&self, publish_message: PublishMessage) -> Result<bool> {
+        let dropped = self.publisher.send(&[
+            publish_message.topic.as_bytes(),
+            self.publisher_address.as_bytes(),
+            &publish_message.data,
+            publish_message.msg_type.as_bytes(),
+        ]);
+        Ok(dropped == 0)
+    }
+
+    /// Publish several messages. It returns how many of them failed.
+    pub(crate) fn publish_batch(&self, msgs: &[PublishMessage]) -> PublishFailures {
+        let publisher_address = self.publisher_address.as_bytes();
+        let dropped = msgs
+            .iter()
+            .filter(|msg| {
+                let dropped = self.publisher.send(&[
+                    msg.topic.as_bytes(),
+                    publisher_address,
+                    &msg.data,
+                    msg.msg_type.as_bytes(),
+                ]);
+                dropped > 0
+            })
+            .count();
+        PublishFailures { dropped, errors: 0 }
+    }
+
+    pub(crate) fn request(&mut self, msg: RequestMessage) -> Result<()> {
+        let address = match msg.replier_address {
+            Some(address) => address.to_string(),
+            None => bail!("RequestMessage.address is None"),
+        };
+        let (runtime, requester) = match (self.runtime.as_ref(), self.requester.as_mut()) {
+            (Some(runtime), Some(requester)) => (runtime, requester),
+            _ => bail!("Transporter is not started"),
+        };
+
+        {
+            let mut srv_connections = self.srv_connections.lock().unwrap();
+            if srv_connections.insert(address.clone()) {
+                let _guard = runtime.enter();
+                requester.connect(&address);
+                debug!("Connected to [{}] for service requests", address);
+            }
+        }
+
+        let my_requester_address = self.requester_address.lock().unwrap().clone();
+        let frames = vec![
+            msg.replier_id.into_bytes(),
+            msg.topic.clone().into_bytes(),
+            my_requester_address.into_bytes(),
+            self.requester_id.clone().into_bytes(),
+            msg.node_uuid.clone().into_bytes(),
+            msg.req_uuid.clone().into_bytes(),
+            msg.data,
+            msg.req_type.into_bytes(),
+            msg.res_type.into_bytes(),
+        ];
+        let sender = requester.sender();
+        if sender.has_peer(&frames[0]) {
+            return sender.send(&frames);
+        }
+
+        // Wait for the handshake of a new connection on the transport runtime,
+        // and answer with a failed response if the replier can't be reached.
+        let failure = ReplyMessage {
+            requester_address: None,
+            requester_id: self.requester_id.clone(),
+            topic: msg.topic,
+            node_uuid: msg.node_uuid,
+            req_uuid: msg.req_uuid,
+            data: vec![],
+            result: false,
+        };
+        let response_handler = self.response_handler.clone();
+        runtime.spawn(async move {
+            let timeout = Duration::from_millis(CONNECT_TIMEOUT);
+            sender.wait_peer(&frames[0], timeout).await;
+            if let Err(e) = sender.send(&frames) {
+                error!("failed requesting: {}", e);
+                if let Some(handler) = response_handler.lock().unwrap().as_ref() {
+                    handler(failure);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    pub(crate) fn reply(&self, msg: ReplyMessage) -> Result<()> {
+        if let Some(sender) = self.reply_msg_sender.as_ref() {
+            sender.send(msg)?;
+            Ok(())
+        } else {
+            bail!("There is no sender. Transporter may not have started.");
+        }
+    }
+
+    pub(crate) fn set_subscription_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(PublishMessage) + Send + 'static,
+    {
+        self.subscription_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    pub(crate) fn set_drop_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&str) + Send + 'static,
+    {
+        self.drop_handler.lock().unwrap().replace(Box::new(handler));
+    }
+
+    pub(crate) fn set_request_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(RequestMessage) + Send + 'static,
+    {
+        self.request_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    pub(crate) fn set_response_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(ReplyMessage) + Send + 'static,
+    {
+        self.response_handler
+            .lock()
+            .unwrap()
+            .replace(Box::new(handler));
+    }
+
+    pub(crate) fn start(&mut self) -> Result<()> {
+        let publisher_listener = match self.publisher_listener.take() {
+            Some(listener) => listener,
+            None => bail!("The transporter was already started."),
+        };
+        // Bind now, so the addresses are known when `start()` returns.
+        let response_listener = bind_any(&self.host_addr)?;
+        let replier_listener = bind_any(&self.host_addr)?;
+        let requester_address = endpoint(&response_listener);
+        let replier_address = endpoint(&replier_listener);
+
+        let mut inner = TransporterInner {
+            requester_id: self.requester_id.clone(),
+            replier_id: self.replier_id.clone(),
+            connections: self.connections.clone(),
+            srv_connections: self.srv_connections.clone(),
+            subscription_handler: self.subscription_handler.clone(),
+            request_handler: self.request_handler.clone(),
+            response_handler: self.response_handler.clone(),
+        };
+        let security = self.security.clone();
+        let publisher = self.publisher.clone();
+        let drop_handler = self.drop_handler.clone();
+
+        let (subscribe_evt_sender, subscribe_evt_receiver) = mpsc::unbounded_channel();
+        let (reply_msg_sender, reply_msg_receiver) = mpsc::unbounded_channel();
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+
+        let (runtime_sender, runtime_receiver) = std::sync::mpsc::channel();
+        let listeners = [publisher_listener, response_listener, replier_listener];
+        let handle = thread::spawn(move || {
+            let (runtime, listeners) = match transport_runtime(listeners) {
+                Ok(started) => started,
+                Err(err) => {
+                    let _ = runtime_sender.send(Err(err));
+                    return;
+                }
+            };
+            if runtime_sender.send(Ok(runtime.handle().clone())).is_err() {
+                return;
+            }
+            let [publisher_listener, response_listener, replier_listener] = listeners;
+
+            runtime.block_on(async move {
+                let (sub_sender, sub_receiver) = mpsc::channel(DEFAULT_RCV_HWM as usize);
+                let (req_sender, req_receiver) = mpsc::unbounded_channel();
+                let (res_sender, res_receiver) = mpsc::unbounded_channel();
+
+                let (pub_mechanism, sub_mechanism) = match security.plain {
+                    Some(plain) => (
+                        Mechanism::PlainServer(plain.clone()),
+                        Mechanism::PlainClient(plain),
+                    ),
+                    None => (Mechanism::Null, Mechanism::Null),
+                };
+                publisher.bind(publisher_listener, pub_mechanism);
+                let mut subscriber = SubSocket::new(sub_mechanism, sub_sender, drop_handler);
+                let mut response_receiver = RouterSocket::new(&inner.requester_id, res_sender);
+                response_receiver.bind(response_listener);
+                let mut replier = RouterSocket::new(&inner.replier_id, req_sender);
+                replier.bind(replier_listener);
+
+                inner
+                    .run(
+                        &mut subscriber,
+                        &mut replier,
+                        subscribe_evt_receiver,
+                        reply_msg_receiver,
+                        sub_receiver,
+                        req_receiver,
+                        res_receiver,
+                        shutdown_receiver,
+                    )
+                    .await;
+            });
+            debug!("Transporter thread finished.");
+        });
+
+        let runtime = runtime_receiver
+            .recv()
+            .context("the transport thread exited")??;
+        let (response_sender, _) = mpsc::unbounded_channel::<Frames>();
+        *self.requester_address.lock().unwrap() = requester_address;
+        *self.replier_address.lock().unwrap() = replier_address;
+        self.subscribe_evt_sender = Some(subscribe_evt_sender);
+        self.reply_msg_sender = Some(reply_msg_sender);
+        self.shutdown_sender = Some(shutdown_sender);
+        self.requester = Some(RouterSocket::new("", response_sender));
+        self.runtime = Some(runtime);
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    /// Stop the runtime thread and wait for it. Its sockets are closed when it exits.
+    pub(crate) async fn stop(&mut self) {
+        if let Some(sender) = self.shutdown_sender.take() {
+            let _ = sender.send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            match tokio::task::spawn_blocking(move || handle.join()).await {
+                Ok(Ok(_)) => {}
+                _ => error!("Transporter thread panicked"),
+            }
+        }
+        self.requester = None;
+        self.runtime = None;
+    }
+}
+
+struct TransporterInner {
+    requester_id: String,
+    replier_id: String,
+
+    connections: Arc<Mutex<HashSet<String>>>,
+    srv_connections: Arc<Mutex<HashSet<String>>>,
+
+    subscription_handler: Arc<Mutex<Option<SubscriptionHandlerType>>>,
+    request_handler: Arc<Mutex<Option<RequestHandlerType>>>,
+    response_handler: Arc<Mutex<Option<ResponseHandlerType>>>,
+}
+
+impl TransporterInner {
+    #[allow(clippy::too_many_arguments)]
+    async fn run(
+        &mut self,
+        subscriber: &mut SubSocket,
+        replier: &mut RouterSocket,
+        mut subscribe_evt_receiver: mpsc::UnboundedReceiver<SubscribeEvent>,
+        mut reply_msg_receiver: mpsc::UnboundedReceiver<ReplyMessage>,
+        mut sub_receiver: mpsc::Receiver<Frames>,
+        mut req_receiver: mpsc::UnboundedReceiver<Frames>,
+        mut res_receiver: mpsc::UnboundedReceiver<Frames>,
+        mut shutdown_receiver: oneshot::Receiver<()>,
+    ) {
+        loop {
+            select! {
+                Some(event) = subscribe_evt_receiver.recv() => {
+                    self.on_subscribe_event(subscriber, event);
+                }
+                Some(msg) = reply_msg_receiver.recv() => {
+                    if let Err(e) = self.reply(replier, msg) {
+                        error!("failed replying: {}", e);
+                    }
+                }
+                Some(frames) = sub_receiver.recv() => self.on_subscribe(frames),
+                Some(frames) = req_receiver.recv() => self.on_request(frames),
+                Some(frames) = res_receiver.recv() => self.on_response(frames),
+                _ = &mut shutdown_receiver => break,
+            }
+        }
+    }
+
+    fn on_subscribe_event(&self, subscriber: &mut SubSocket, event: SubscribeEvent) {
+        if let Some(address) = event.connect {
+            if self.connections.lock().unwrap().insert(address.clone()) {
+                subscriber.connect(&address);
+                debug!("Connected to [{}] for subscriptions", address);
+            } else {
+                debug!("Already connected");
+            }
+        }
+        if let Some(address) = event.disconnect {
+            self.connections.lock().unwrap().remove(&address);
+            subscriber.disconnect(&address);
+        }
+        if let Some(topic) = event.subscribe {
+            subscriber.subscribe(topic.as_bytes());
+        }
+        if let Some(topic) = event.unsubscribe {
+            subscriber.unsubscribe(topic.as_bytes());
+        }
+    }
+
+    fn reply(&mut self, replier: &mut RouterSocket, msg: ReplyMessage) -> Result<()> {
+        let address = match msg.requester_address {
+            Some(address) => address.to_string(),
+            None => bail!("ReplyMessage.address is None"),
+        };
+
+        if self.srv_connections.lock().unwrap().insert(address.clone()) {
+            replier.connect(&address);
+            debug!("Connected to [{}] for service response", address);
+        }
+
+        let result_str = if msg.result { "1" } else { "0" };
+        let frames = vec![
+            msg.requester_id.into_bytes(),
+            msg.topic.into_bytes(),
+            msg.node_uuid.into_bytes(),
+            msg.req_uuid.into_bytes(),
+            msg.data,
+            result_str.as_bytes().to_vec(),
+        ];
+        let sender = replier.sender();
+        if sender.has_peer(&frames[0]) {
+            return sender.send(&frames);
+        }
+        // Wait for the handshake of a new connection without holding up the
+        // other events.
+        tokio::spawn(async move {
+            let timeout = Duration::from_millis(CONNECT_TIMEOUT);
+            sender.wait_peer(&frames[0], timeout).await;
+            if let Err(e) = sender.send(&frames) {
+                error!("failed replying: {}", e);
+            }
+        });
+        Ok(())
+    }
+
+    fn on_subscribe(&self, frames: Frames) {
+        if frames.len() != 4 {
+            error!(
+                "Unexpected number of frames [{}] in a message",
+                frames.len()
+            );
+            return;
+        }
+        if let Some(handler) = self.subscription_handler.lock().unwrap().as_ref() {
+            handler(PublishMessage {
+                topic: to_string(&frames[0]),
+                publisher_address: to_string(&frames[1]),
+                data: frames[2].clone(),
+                msg_type: to_string(&frames[3]),
+            });
+        }
+    }
+
+    fn on_request(&self, frames: Frames) {
+        if frames.len() != 9 {
+            error!(
+                "Unexpected number of frames [{}] in a request",
+                frames.len()
+            );
+            return;
+        }
+        if let Some(handler) = self.request_handler.lock().unwrap().as_ref() {
+            handler(RequestMessage {
+                replier_address: None,
+                replier_id: to_string(&frames[0]),
+                topic: to_string(&frames[1]),
+                requester_address: to_string(&frames[2]),
+                requester_id: to_string(&frames[3]),
+                node_uuid: to_string(&frames[4]),
+                req_uuid: to_string(&frames[5]),
+                data: frames[6].clone(),
+                req_type: to_string(&frames[7]),
+                res_type: to_string(&frames[8]),
+            });
+        }
+    }
+
+    fn on_response(&self, frames: Frames) {
+        if frames.len() != 6 {
+            error!(
+                "Unexpected number of frames [{}] in a response",
+                frames.len()
+            );
+            return;
+        }
+        if let Some(handler) = self.response_handler.lock().unwrap().as_ref() {
+            handler(ReplyMessage {
+                requester_address: None,
+                requester_id: to_string(&frames[0]),
+                topic: to_string(&frames[1]),
+                node_uuid: to_string(&frames[2]),
+                req_uuid: to_string(&frames[3]),
+                data: frames[4].clone(),
+                result: frames[5] == b"1",
+            });
+        }
+    }
+}
+
+fn to_string(frame: &[u8]) -> String {
+    String::from_utf8_lossy(frame).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{CurveKeys, PlainCredentials};
+
+    const IP: &str = "127.0.0.1";
+    const TOPIC: &str = "topic";
+
+    fn publish_message(address: &str) -> PublishMessage {
+        PublishMessage {
+            topic: TOPIC.to_string(),
+            publisher_address: address.to_string(),
+            msg_type: "Person".to_string(),
+            data: vec![1, 2, 3],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pub_sub() {
+        let plain = Security {
+            plain: Some(PlainCredentials::new("user", "pass")),
+            curve: None,
+        };
+        for security in [Security::default(), plain] {
+            let mut transporter1 = Transporter::with_security(IP, security.clone()).unwrap();
+            let mut transporter2 = Transporter::with_security(IP, security).unwrap();
+            transporter1.start().unwrap();
+            transporter2.start().unwrap();
+            let address = transporter1.publisher_address();
+
+            let (sender, mut receiver) = mpsc::unbounded_channel();
+            transporter2.set_subscription_handler(move |msg| {
+                sender.send(msg).unwrap();
+            });
+            transporter2
+                .subscribe(Some(&address), None, Some(TOPIC), None)
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            transporter1.publish(publish_message(&address)).unwrap();
+            let msg = tokio::time::timeout(Duration::from_millis(1000), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(msg.topic, TOPIC);
+            assert_eq!(msg.publisher_address, address);
+            assert_eq!(msg.data, vec![1, 2, 3]);
+            assert_eq!(msg.msg_type, "Person");
+
+            transporter1.stop().await;
+            transporter2.stop().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pub_sub_wrong_password() {
+        let mut transporter1 = Transporter::with_security(
+            IP,
+            Security {
+                plain: Some(PlainCredentials::new("user", "pass")),
+                curve: None,
+            },
+        )
+        .unwrap();
+        let mut transporter2 = Transporter::with_security(
+            IP,
+            Security {
+                plain: Some(PlainCredentials::new("user", "wrong")),
+                curve: None,
+            },
+        )
+        .unwrap();
+        transporter1.start().unwrap();
+        transporter2.start().unwrap();
+        let address = transporter1.publisher_address();
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        transporter2.set_subscription_handler(move |msg| {
+            sender.send(msg).unwrap();
+        });
+        transporter2
+            .subscribe(Some(&address), None, Some(TOPIC), None)
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        transporter1.publish(publish_message(&address)).unwrap();
+        let result = tokio::time::timeout(Duration::from_millis(300), receiver.recv()).await;
+        assert!(result.is_err());
+
+        transporter1.stop().await;
+        transporter2.stop().await;
+    }
+
+    #[tokio::test]
+    async fn test_receive_hwm() {
+        let std_listener = bind_any(IP).unwrap();
+        let address = endpoint(&std_listener);
+        let publisher = PubSocket::new();
+        publisher.bind(
+            TcpListener::from_std(std_listener).unwrap(),
+            Mechanism::Null,
+        );
+
+        let dropped = Arc::new(Mutex::new(Vec::new()));
+        let drop_handler: DropHandlerType = {
+            let dropped = dropped.clone();
+            Box::new(move |topic| dropped.lock().unwrap().push(topic.to_string()))
+        };
+        // A receive buffer of one message that is never read.
+        let (sender, _receiver) = mpsc::channel(1);
+        let mut subscriber = SubSocket::new(
+            Mechanism::Null,
+            sender,
+            Arc::new(Mutex::new(Some(drop_handler))),
+        );
+        subscriber.subscribe(TOPIC.as_bytes());
+        subscriber.connect(&address);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        for _ in 0..3 {
+            assert_eq!(publisher.send(&[TOPIC.as_bytes(), b"data"]), 0);
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(*dropped.lock().unwrap(), vec![TOPIC, TOPIC]);
+    }
+
+    #[tokio::test]
+    async fn test_req_res() {
+        let mut transporter1 = Transporter::new(IP).unwrap();
+        let mut transporter2 = Transporter::new(IP).unwrap();
+        transporter1.start().unwrap();
+        transporter2.start().unwrap();
+
+        let (req_sender, mut req_receiver) = mpsc::unbounded_channel();
+        transporter2.set_request_handler(move |msg| {
+            req_sender.send(msg).unwrap();
+        });
+        let (res_sender, mut res_receiver) = mpsc::unbounded_channel();
+        transporter1.set_response_handler(move |msg| {
+            res_sender.send(msg).unwrap();
+        });
+
+        transporter1
+            .request(RequestMessage {
+                replier_address: Some(transporter2.replier_address()),
+                replier_id: transporter2.replier_id(),
+                topic: TOPIC.to_string(),
+                requester_address: "".to_string(),
+                requester_id: "".to_string(),
+                node_uuid: "node".to_string(),
+                req_uuid: "req".to_string(),
+                data: vec![1],
+                req_type: "Request".to_string(),
+                res_type: "Response".to_string(),
+            })
+            .unwrap();
+
+        let req = tokio::time::timeout(Duration::from_millis(1000), req_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.topic, TOPIC);
+        assert_eq!(req.data, vec![1]);
+        assert_eq!(req.req_type, "Request");
+
+        transporter2
+            .reply(ReplyMessage {
+                requester_address: Some(req.requester_address),
+                requester_id: req.requester_id,
+                topic: req.topic,
+                node_uuid: req.node_uuid,
+                req_uuid: req.req_uuid,
+                data: vec![2],
+                result: true,
+            })
+            .unwrap();
+
+        let res = tokio::time::timeout(Duration::from_millis(1000), res_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.req_uuid, "req");
+        assert_eq!(res.data, vec![2]);
+        assert!(res.result);
+
+        transporter1.stop().await;
+        transporter2.stop().await;
+    }
+
+    // A request to an unreachable replier doesn't block, it fails with a response.
+    #[tokio::test]
+    async fn test_request_unreachable() {
+        let mut transporter = Transporter::new(IP).unwrap();
+        transporter.start().unwrap();
+        let (res_sender, mut res_receiver) = mpsc::unbounded_channel();
+        transporter.set_response_handler(move |msg| {
+            res_sender.send(msg).unwrap();
+        });
+        let closed = endpoint(&bind_any(IP).unwrap());
+
+        let start = std::time::Instant::now();
+        transporter
+            .request(RequestMessage {
+                replier_address: Some(closed),
+                replier_id: "replier".to_string(),
+                topic: TOPIC.to_string(),
+                requester_address: "".to_string(),
+                requester_id: "".to_string(),
+                node_uuid: "node".to_string(),
+                req_uuid: "req".to_string(),
+                data: vec![1],
+                req_type: "Request".to_string(),
+                res_type: "Response".to_string(),
+            })
+            .unwrap();
+        assert!(start.elapsed() < Duration::from_millis(CONNECT_TIMEOUT / 2));
+
+        let res = tokio::time::timeout(Duration::from_millis(2000), res_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.req_uuid, "req");
+        assert!(!res.result);
+
+        transporter.stop().await;
+    }
+
+    #[test]
+    fn test_curve_unsupported() {
+        let key = vec![0; 32];
+        let security = Security {
+            plain: None,
+            curve: Some(CurveKeys {
+                public_key: key.clone(),
+                secret_key: key.clone(),
+                server_key: key,
+            }),
+        };
+        assert!(Transporter::with_security(IP, security).is_err());
+    }
+
+    // Check the wire compatibility with libzmq.
+    #[cfg(feature = "zmq")]
+    #[tokio::test]
+    async fn test_libzmq_interop() {
+        let mut transporter = Transporter::new(IP).unwrap();
+        transporter.start().unwrap();
+        let address = transporter.publisher_address();
+
+        // libzmq subscriber, rgz publisher.
+        let context = zmq::Context::new();
+        let subscriber = context.socket(zmq::SUB).unwrap();
+        subscriber.set_rcvtimeo(1000).unwrap();
+        subscriber.connect(&address).unwrap();
+        subscriber.set_subscribe(TOPIC.as_bytes()).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        transporter.publish(publish_message(&address)).unwrap();
+        let frames = subscriber.recv_multipart(0).unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], TOPIC.as_bytes());
+        assert_eq!(frames[2], vec![1, 2, 3]);
+
+        // libzmq publisher, rgz subscriber.
+        let publisher = context.socket(zmq::PUB).unwrap();
+        publisher.bind(&format!("tcp://{}:*", IP)).unwrap();
+        let zmq_address = publisher.get_last_endpoint().unwrap().unwrap();
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        transporter.set_subscription_handler(move |msg| {
+            sender.send(msg).unwrap();
+        });
+        transporter
+            .subscribe(Some(&zmq_address), None, Some(TOPIC), None)
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        publisher
+            .send_multipart(
+                [TOPIC.as_bytes(), zmq_address.as_bytes(), b"data", b"Person"],
+                0,
+            )
+            .unwrap();
+        let msg = tokio::time::timeout(Duration::from_millis(1000), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.publisher_address, zmq_address);
+        assert_eq!(msg.data, b"data");
+
+        // libzmq requester, rgz replier.
+        let (req_sender, mut req_receiver) = mpsc::unbounded_channel();
+        transporter.set_request_handler(move |msg| {
+            req_sender.send(msg).unwrap();
+        });
+        let requester = context.socket(zmq::ROUTER).unwrap();
+        requester.set_router_mandatory(true).unwrap();
+        requester.connect(&transporter.replier_address()).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        requester
+            .send_multipart(
+                [
+                    transporter.replier_id().as_bytes(),
+                    TOPIC.as_bytes(),
+                    b"tcp://127.0.0.1:1",
+                    b"requester",
+                    b"node",
+                    b"req",
+                    b"data",
+                    b"Request",
+                    b"Response",
+                ],
+                0,
+            )
+            .unwrap();
+        let req = tokio::time::timeout(Duration::from_millis(1000), req_receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.requester_id, "requester");
+        assert_eq!(req.res_type, "Response");
+
+        transporter.stop().await;
+    }
+}
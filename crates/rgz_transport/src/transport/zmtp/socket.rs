@@ -0,0 +1,538 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{bail, Result};
+use tokio::io::{AsyncWriteExt, ReadHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::{self, error::TrySendError, Receiver, Sender, UnboundedSender};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tokio::time::{self, Duration};
+use tracing::{debug, trace};
+
+use super::codec::{encode_message, handshake, read_incoming, Incoming, Mechanism};
+use crate::transport::{DropHandlerType, DEFAULT_SND_HWM};
+
+/// Time between reconnection attempts, as the libzmq default (ms.).
+const RECONNECT_IVL: u64 = 100;
+
+/// Maximum duration of a handshake (ms.).
+const HANDSHAKE_TIMEOUT: u64 = 5000;
+
+pub(super) type Frames = Vec<Vec<u8>>;
+
+static NEXT_PEER_ID: AtomicU64 = AtomicU64::new(1);
+
+fn tcp_address(endpoint: &str) -> &str {
+    endpoint.trim_start_matches("tcp://")
+}
+
+async fn connect_and_handshake(
+    endpoint: &str,
+    mechanism: &Mechanism,
+    socket_type: &str,
+    identity: &[u8],
+) -> Result<(TcpStream, Vec<u8>)> {
+    let mut stream = TcpStream::connect(tcp_address(endpoint)).await?;
+    stream.set_nodelay(true)?;
+    let peer_identity = timed_handshake(&mut stream, mechanism, socket_type, identity).await?;
+    Ok((stream, peer_identity))
+}
+
+async fn timed_handshake(
+    stream: &mut TcpStream,
+    mechanism: &Mechanism,
+    socket_type: &str,
+    identity: &[u8],
+) -> Result<Vec<u8>> {
+    let timeout = Duration::from_millis(HANDSHAKE_TIMEOUT);
+    let metadata =
+        match time::timeout(timeout, handshake(stream, mechanism, socket_type, identity)).await {
+            Ok(metadata) => metadata?,
+            Err(_) => bail!("Handshake timed out"),
+        };
+    Ok(metadata.get("Identity").cloned().unwrap_or_default())
+}
+
+// Write the encoded messages of a channel into the peer's socket.
+fn spawn_writer<W>(mut writer: W, mut receiver: Receiver<Vec<u8>>) -> JoinHandle<()>
+where
+    W: AsyncWriteExt + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        while let Some(buf) = receiver.recv().await {
+            if writer.write_all(&buf).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+struct PubPeer {
+    subscriptions: Vec<Vec<u8>>,
+    sender: Sender<Vec<u8>>,
+}
+
+/// ZMTP PUB socket. Messages are filtered by the subscriptions of each peer.
+pub(super) struct PubSocket {
+    peers: Arc<Mutex<HashMap<u64, PubPeer>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PubSocket {
+    pub(super) fn new() -> Self {
+        PubSocket {
+            peers: Arc::new(Mutex::new(HashMap::new())),
+            handle: Mutex::new(None),
+        }
+    }
+
+    pub(super) fn bind(&self, listener: TcpListener, mechanism: Mechanism) {
+        let peers = self.peers.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                trace!("PUB accepted connection from [{}]", addr);
+                tokio::spawn(Self::serve(stream, mechanism.clone(), peers.clone()));
+            }
+        });
+        self.handle.lock().unwrap().replace(handle);
+    }
+
+    async fn serve(
+        mut stream: TcpStream,
+        mechanism: Mechanism,
+        peers: Arc<Mutex<HashMap<u64, PubPeer>>>,
+    ) {
+        let _ = stream.set_nodelay(true);
+        if let Err(e) = timed_handshake(&mut stream, &mechanism, "PUB", b"").await {
+            debug!("PUB handshake failed: {}", e);
+            return;
+        }
+        let (mut reader, writer) = tokio::io::split(stream);
+        let (sender, receiver) = mpsc::channel(DEFAULT_SND_HWM as usize);
+        let writer_handle = spawn_writer(writer, receiver);
+
+        let id = NEXT_PEER_ID.fetch_add(1, Ordering::SeqCst);
+        peers.lock().unwrap().insert(
+            id,
+            PubPeer {
+                subscriptions: Vec::new(),
+                sender,
+            },
+        );
+
+        while let Ok(incoming) = read_incoming(&mut reader).await {
+            let (subscribe, topic) = match incoming {
+                Incoming::Command(name, topic) if name == "SUBSCRIBE" => (true, topic),
+                Incoming::Command(name, topic) if name == "CANCEL" => (false, topic),
+                Incoming::Message(mut frames) if frames.len() == 1 && !frames[0].is_empty() => {
+                    let body = frames.remove(0);
+                    (body[0] == 1, body[1..].to_vec())
+                }
+                _ => continue,
+            };
+            let mut peers = peers.lock().unwrap();
+            if let Some(peer) = peers.get_mut(&id) {
+                if subscribe {
+                    peer.subscriptions.push(topic);
+                } else if let Some(i) = peer.subscriptions.iter().position(|t| *t == topic) {
+                    peer.subscriptions.remove(i);
+                }
+            }
+        }
+        peers.lock().unwrap().remove(&id);
+        writer_handle.abort();
+    }
+
+    /// Send a message to the subscribed peers. Like libzmq, messages are
+    /// dropped for peers that reached the high water mark.
+    /// It returns the number of peers that dropped the message.
+    pub(super) fn send<T: AsRef<[u8]>>(&self, frames: &[T]) -> usize {
+        let topic = match frames.first() {
+            Some(topic) => topic.as_ref(),
+            None => return 0,
+        };
+        let mut buf = None;
+        let mut dropped = 0;
+        let mut peers = self.peers.lock().unwrap();
+        peers.retain(|_, peer| {
+            if !peer.subscriptions.iter().any(|s| topic.starts_with(s)) {
+                return true;
+            }
+            let buf = buf.get_or_insert_with(|| encode_message(frames));
+            match peer.sender.try_send(buf.clone()) {
+                Err(TrySendError::Closed(_)) => false,
+                Err(TrySendError::Full(_)) => {
+                    dropped += 1;
+                    true
+                }
+                Ok(_) => true,
+            }
+        });
+        dropped
+    }
+}
+
+impl Drop for PubSocket {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+struct SubConnection {
+    sender: UnboundedSender<Vec<u8>>,
+    handle: JoinHandle<()>,
+}
+
+/// ZMTP SUB socket connected to any number of publishers.
+pub(super) struct SubSocket {
+    mechanism: Mechanism,
+    subscriptions: Arc<Mutex<Vec<Vec<u8>>>>,
+    connections: HashMap<String, SubConnection>,
+    incoming: Sender<Frames>,
+    drop_handler: Arc<Mutex<Option<DropHandlerType>>>,
+}
+
+impl SubSocket {
+    pub(super) fn new(
+        mechanism: Mechanism,
+        incoming: Sender<Frames>,
+        drop_handler: Arc<Mutex<Option<DropHandlerType>>>,
+    ) -> Self {
+        SubSocket {
+            mechanism,
+            subscriptions: Arc::new(Mutex::new(Vec::new())),
+            connections: HashMap::new(),
+            incoming,
+            drop_handler,
+        }
+    }
+
+    pub(super) fn connect(&mut self, endpoint: &str) {
+        if self.connections.contains_key(endpoint) {
+            return;
+        }
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        let endpoint_clone = endpoint.to_string();
+        let mechanism = self.mechanism.clone();
+        let subscriptions = self.subscriptions.clone();
+        let incoming = self.incoming.clone();
+        let drop_handler = self.drop_handler.clone();
+
+        let handle = tokio::spawn(async move {
+            let endpoint = endpoint_clone;
+            loop {
+                let stream = match connect_and_handshake(&endpoint, &mechanism, "SUB", b"").await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        trace!("SUB connection to [{}] failed: {}", endpoint, e);
+                        time::sleep(Duration::from_millis(RECONNECT_IVL)).await;
+                        continue;
+                    }
+                };
+                let (mut reader, mut writer) = tokio::io::split(stream);
+
+                // Pending changes are already part of the current subscriptions.
+                let current = {
+                    let subscriptions = subscriptions.lock().unwrap();
+                    while receiver.try_recv().is_ok() {}
+                    subscriptions.clone()
+                };
+                let mut buf = Vec::new();
+                for topic in current {
+                    buf.extend(subscription_message(true, &topic));
+                }
+                if writer.write_all(&buf).await.is_err() {
+                    continue;
+                }
+
+                Self::serve(
+                    &mut reader,
+                    &mut writer,
+                    &mut receiver,
+                    &subscriptions,
+                    &incoming,
+                    &drop_handler,
+                )
+                .await;
+                debug!("SUB disconnected from [{}]", endpoint);
+                time::sleep(Duration::from_millis(RECONNECT_IVL)).await;
+            }
+        });
+
+        self.connections
+            .insert(endpoint.to_string(), SubConnection { sender, handle });
+    }
+
+    async fn serve<W: AsyncWriteExt + Unpin>(
+        reader: &mut ReadHalf<TcpStream>,
+        writer: &mut W,
+        receiver: &mut mpsc::UnboundedReceiver<Vec<u8>>,
+        subscriptions: &Mutex<Vec<Vec<u8>>>,
+        incoming: &Sender<Frames>,
+        drop_handler: &Mutex<Option<DropHandlerType>>,
+    ) {
+        // Reading is not cancel safe, so the same future is polled until the end.
+        let read_loop = Self::read_loop(reader, subscriptions, incoming, drop_handler);
+        tokio::pin!(read_loop);
+        loop {
+            tokio::select! {
+                Some(buf) = receiver.recv() => {
+                    if writer.write_all(&buf).await.is_err() {
+                        return;
+                    }
+                }
+                _ = &mut read_loop => return,
+            }
+        }
+    }
+
+    async fn read_loop(
+        reader: &mut ReadHalf<TcpStream>,
+        subscriptions: &Mutex<Vec<Vec<u8>>>,
+        incoming: &Sender<Frames>,
+        drop_handler: &Mutex<Option<DropHandlerType>>,
+    ) {
+        loop {
+            let frames = match read_incoming(reader).await {
+                Ok(Incoming::Message(frames)) => frames,
+                Ok(Incoming::Command(..)) => continue,
+                Err(_) => return,
+            };
+            let topic = frames.first().cloned().unwrap_or_default();
+            if subscriptions
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|s| topic.starts_with(s))
+            {
+                // Drop the message if the receive buffer is full, as libzmq.
+                match incoming.try_send(frames) {
+                    Ok(_) => {}
+                    Err(TrySendError::Full(_)) => {
+                        trace!("Receive buffer full, dropped a message");
+                        if let Some(handler) = drop_handler.lock().unwrap().as_ref() {
+                            handler(&String::from_utf8_lossy(&topic));
+                        }
+                    }
+                    Err(TrySendError::Closed(_)) => return,
+                }
+            }
+        }
+    }
+
+    pub(super) fn disconnect(&mut self, endpoint: &str) {
+        if let Some(connection) = self.connections.remove(endpoint) {
+            connection.handle.abort();
+        }
+    }
+
+    pub(super) fn subscribe(&mut self, topic: &[u8]) {
+        self.update(true, topic);
+    }
+
+    pub(super) fn unsubscribe(&mut self, topic: &[u8]) {
+        self.update(false, topic);
+    }
+
+    fn update(&mut self, subscribe: bool, topic: &[u8]) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if subscribe {
+            subscriptions.push(topic.to_vec());
+        } else if let Some(i) = subscriptions.iter().position(|t| t == topic) {
+            subscriptions.remove(i);
+        } else {
+            return;
+        }
+        let buf = subscription_message(subscribe, topic);
+        for connection in self.connections.values() {
+            let _ = connection.sender.send(buf.clone());
+        }
+    }
+}
+
+impl Drop for SubSocket {
+    fn drop(&mut self) {
+        for connection in self.connections.values() {
+            connection.handle.abort();
+        }
+    }
+}
+
+// ZMTP 3.0 subscriptions are messages starting with 1 (subscribe) or 0 (cancel).
+fn subscription_message(subscribe: bool, topic: &[u8]) -> Vec<u8> {
+    let mut body = vec![subscribe as u8];
+    body.extend_from_slice(topic);
+    encode_message(&[body])
+}
+
+type RouterPeers = Mutex<HashMap<Vec<u8>, UnboundedSender<Vec<u8>>>>;
+
+/// Sends messages through the connections of a `RouterSocket`, from any task.
+#[derive(Clone)]
+pub(super) struct RouterSender {
+    peers: Arc<RouterPeers>,
+    // Notified when a handshake completes.
+    peer_added: Arc<Notify>,
+}
+
+impl RouterSender {
+    pub(super) fn has_peer(&self, identity: &[u8]) -> bool {
+        self.peers.lock().unwrap().contains_key(identity)
+    }
+
+    /// Wait until the peer is connected. It returns false on timeout.
+    pub(super) async fn wait_peer(&self, identity: &[u8], timeout: Duration) -> bool {
+        let deadline = time::Instant::now() + timeout;
+        loop {
+            // Registered before the check, so that no handshake is missed.
+            let notified = self.peer_added.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.has_peer(identity) {
+                return true;
+            }
+            if time::timeout_at(deadline, notified).await.is_err() {
+                return self.has_peer(identity);
+            }
+        }
+    }
+
+    /// Send a message to the peer named by the first frame. It fails if the
+    /// peer is unknown, as a libzmq ROUTER with `ZMQ_ROUTER_MANDATORY`.
+    pub(super) fn send<T: AsRef<[u8]>>(&self, frames: &[T]) -> Result<()> {
+        let identity = match frames.first() {
+            Some(identity) => identity.as_ref(),
+            None => bail!("Empty message"),
+        };
+        let peers = self.peers.lock().unwrap();
+        match peers.get(identity) {
+            Some(sender) => {
+                sender.send(encode_message(&frames[1..]))?;
+                Ok(())
+            }
+            None => bail!("Host unreachable [{}]", String::from_utf8_lossy(identity)),
+        }
+    }
+}
+
+/// ZMTP ROUTER socket. The first frame of a message is the identity of a peer.
+pub(super) struct RouterSocket {
+    identity: Vec<u8>,
+    sender: RouterSender,
+    incoming: UnboundedSender<Frames>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl RouterSocket {
+    pub(super) fn new(identity: &str, incoming: UnboundedSender<Frames>) -> Self {
+        RouterSocket {
+            identity: identity.as_bytes().to_vec(),
+            sender: RouterSender {
+                peers: Arc::new(Mutex::new(HashMap::new())),
+                peer_added: Arc::new(Notify::new()),
+            },
+            incoming,
+            handles: Vec::new(),
+        }
+    }
+
+    pub(super) fn bind(&mut self, listener: TcpListener) {
+        let identity = self.identity.clone();
+        let sender = self.sender.clone();
+        let incoming = self.incoming.clone();
+        self.handles.push(tokio::spawn(async move {
+            while let Ok((mut stream, addr)) = listener.accept().await {
+                trace!("ROUTER accepted connection from [{}]", addr);
+                let identity = identity.clone();
+                let sender = sender.clone();
+                let incoming = incoming.clone();
+                tokio::spawn(async move {
+                    let _ = stream.set_nodelay(true);
+                    match timed_handshake(&mut stream, &Mechanism::Null, "ROUTER", &identity).await
+                    {
+                        Ok(peer_identity) => {
+                            Self::serve(stream, peer_identity, &sender, &incoming).await
+                        }
+                        Err(e) => debug!("ROUTER handshake failed: {}", e),
+                    }
+                });
+            }
+        }));
+    }
+
+    pub(super) fn connect(&mut self, endpoint: &str) {
+        let endpoint = endpoint.to_string();
+        let identity = self.identity.clone();
+        let sender = self.sender.clone();
+        let incoming = self.incoming.clone();
+        self.handles.push(tokio::spawn(async move {
+            loop {
+                match connect_and_handshake(&endpoint, &Mechanism::Null, "ROUTER", &identity).await
+                {
+                    Ok((stream, peer_identity)) => {
+                        Self::serve(stream, peer_identity, &sender, &incoming).await;
+                        debug!("ROUTER disconnected from [{}]", endpoint);
+                    }
+                    Err(e) => trace!("ROUTER connection to [{}] failed: {}", endpoint, e),
+                }
+                time::sleep(Duration::from_millis(RECONNECT_IVL)).await;
+            }
+        }));
+    }
+
+    async fn serve(
+        stream: TcpStream,
+        mut peer_identity: Vec<u8>,
+        router: &RouterSender,
+        incoming: &UnboundedSender<Frames>,
+    ) {
+        if peer_identity.is_empty() {
+            // Same format as the identities generated by libzmq.
+            peer_identity = vec![0];
+            let id = NEXT_PEER_ID.fetch_add(1, Ordering::SeqCst) as u32;
+            peer_identity.extend_from_slice(&id.to_be_bytes());
+        }
+        let (mut reader, mut writer) = tokio::io::split(stream);
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        router
+            .peers
+            .lock()
+            .unwrap()
+            .insert(peer_identity.clone(), sender);
+        router.peer_added.notify_waiters();
+
+        let writer_handle = tokio::spawn(async move {
+            while let Some(buf) = receiver.recv().await {
+                if writer.write_all(&buf).await.is_err() {
+                    break;
+                }
+            }
+        });
+        while let Ok(incoming_msg) = read_incoming(&mut reader).await {
+            if let Incoming::Message(mut frames) = incoming_msg {
+                frames.insert(0, peer_identity.clone());
+                if incoming.send(frames).is_err() {
+                    break;
+                }
+            }
+        }
+        router.peers.lock().unwrap().remove(&peer_identity);
+        writer_handle.abort();
+    }
+
+    pub(super) fn sender(&self) -> RouterSender {
+        self.sender.clone()
+    }
+}
+
+impl Drop for RouterSocket {
+    fn drop(&mut self) {
+        for handle in self.handles.iter() {
+            handle.abort();
+        }
+    }
+}
@@ -0,0 +1,22 @@
+// The transports are global to the process, so the shutdown is tested in its
+// own test binary.
+
+use std::time::Duration;
+
+use rgz_msgs::StringMsg;
+use rgz_transport::{Node, ShutdownGuard};
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_shutdown_guard() {
+    let guard = ShutdownGuard::new();
+    let node = Node::new(None);
+    let publisher = node.advertise::<StringMsg>("/shutdown", None).unwrap();
+    while !publisher.is_ready() {
+        sleep(Duration::from_millis(50)).await;
+    }
+    publisher.publish(StringMsg::default()).unwrap();
+
+    guard.close().await.unwrap();
+    assert!(publisher.publish(StringMsg::default()).is_err());
+}
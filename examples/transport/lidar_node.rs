@@ -4,7 +4,7 @@
 ///
 
 use anyhow::Result;
-use rgz::msgs::{LaserScan, Twist, Vector3d};
+use rgz::msgs::{LaserScan, Twist};
 use rgz::transport::Node;
 use tokio::signal;
 
@@ -17,27 +17,10 @@ async fn main() -> Result<()> {
     let topic_sub = "/lidar";
     node.subscribe(topic_sub, move |msg: LaserScan| {
         let all_more = msg.ranges.iter().all(|&range| range >= 1.0);
-        let mut twist = Twist {
-            ..Default::default()
-        };
-        if all_more {
-            twist.linear = Some(Vector3d {
-                x: 0.5,
-                ..Default::default()
-            });
-            twist.angular = Some(Vector3d {
-                z: 0.0,
-                ..Default::default()
-            });
+        let twist = if all_more {
+            Twist::new([0.5, 0.0, 0.0], [0.0, 0.0, 0.0])
         } else {
-            twist.linear = Some(Vector3d {
-                x: 0.0,
-                ..Default::default()
-            });
-            twist.angular = Some(Vector3d {
-                z: 0.5,
-                ..Default::default()
-            });
+            Twist::new([0.0, 0.0, 0.0], [0.0, 0.0, 0.5])
         };
         publisher.publish(twist).unwrap();
     })?;